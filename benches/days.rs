@@ -0,0 +1,69 @@
+//! One group per day with the parsing and each part measured separately.
+//! Uses the real input when it's in `resources/`, the example otherwise.
+//! Days without any input are skipped.
+use advent_of_rust::{
+    utils::read_challenge_data,
+    year2021::{
+        day1::Day1, day10::Day10, day11::Day11, day12::Day12, day13::Day13, day14::Day14,
+        day15::Day15, day16::Day16, day17::Day17, day18::Day18, day19::Day19, day2::Day2,
+        day20::Day20, day21::Day21, day22::Day22, day23::Day23, day24::Day24, day25::Day25,
+        day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8, day9::Day9,
+    },
+    Solution,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let (input, kind) = match read_challenge_data(S::YEAR, S::DAY, false) {
+        Ok(input) => (input, "input"),
+        Err(_) => match read_challenge_data(S::YEAR, S::DAY, true) {
+            Ok(input) => (input, "example"),
+            Err(_) => return,
+        },
+    };
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Skipping day {}: {}", S::DAY, e);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("{}/day{:02}/{}", S::YEAR, S::DAY, kind));
+    // Some days take seconds per iteration
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<Day1>,
+    bench_day::<Day2>,
+    bench_day::<Day3>,
+    bench_day::<Day4>,
+    bench_day::<Day5>,
+    bench_day::<Day6>,
+    bench_day::<Day7>,
+    bench_day::<Day8>,
+    bench_day::<Day9>,
+    bench_day::<Day10>,
+    bench_day::<Day11>,
+    bench_day::<Day12>,
+    bench_day::<Day13>,
+    bench_day::<Day14>,
+    bench_day::<Day15>,
+    bench_day::<Day16>,
+    bench_day::<Day17>,
+    bench_day::<Day18>,
+    bench_day::<Day19>,
+    bench_day::<Day20>,
+    bench_day::<Day21>,
+    bench_day::<Day22>,
+    bench_day::<Day23>,
+    bench_day::<Day24>,
+    bench_day::<Day25>,
+);
+criterion_main!(days);
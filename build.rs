@@ -0,0 +1,51 @@
+//! Generates one test per entry of `answers.toml`, included by `tests/answers.rs`
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=answers.toml");
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    // A missing file means there are no known answers yet
+    let content =
+        fs::read_to_string(Path::new(&manifest_dir).join("answers.toml")).unwrap_or_default();
+    let table: toml::Value = content
+        .parse()
+        .unwrap_or_else(|e| panic!("answers.toml is not valid TOML: {}", e));
+
+    let mut tests = String::new();
+    let entries = table.get("answer").and_then(|a| a.as_array());
+    for entry in entries.into_iter().flatten() {
+        let field = |name: &str| {
+            entry
+                .get(name)
+                .unwrap_or_else(|| panic!("answers.toml: an entry has no `{}`: {}", name, entry))
+        };
+        // Same default as `solution::DEFAULT_YEAR`
+        let year = entry
+            .get("year")
+            .map_or(2021, |year| year.as_integer().expect("`year` is a number"));
+        let day = field("day").as_integer().expect("`day` is a number");
+        let part = field("part").as_integer().expect("`part` is a number");
+        let input = match field("input").as_str() {
+            Some("example") => "Example",
+            Some("real") => "Real",
+            _ => panic!(
+                "answers.toml: `input` is \"example\" or \"real\": {}",
+                entry
+            ),
+        };
+        tests.push_str(&format!(
+            "answer_test!(year{}_day{:02}_part{}_{}, {}, {}, {}, {});\n",
+            year,
+            day,
+            part,
+            input.to_lowercase(),
+            year,
+            day,
+            part,
+            input
+        ));
+    }
+    fs::write(Path::new(&out_dir).join("answer_tests.rs"), tests).unwrap();
+}
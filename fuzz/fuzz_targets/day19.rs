@@ -0,0 +1,8 @@
+#![no_main]
+use advent_of_rust::{year2021::day19::Day19, Solution};
+use libfuzzer_sys::fuzz_target;
+
+// Errors are the expected answer to garbage, only a panic is a bug
+fuzz_target!(|input: &str| {
+    let _ = Day19::parse(input);
+});
@@ -0,0 +1,128 @@
+use std::{borrow::Cow, fmt};
+
+use num::{BigInt, ToPrimitive};
+
+/// What a part of a puzzle evaluates to.
+/// Integers that don't fit in an `i64` become `BigInt`s so equal answers always compare equal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    BigInt(BigInt),
+    /// `Cow` so known answers can be written in consts
+    Text(Cow<'static, str>),
+    /// ASCII art, one string per row
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub const fn text(s: &'static str) -> Self {
+        Answer::Text(Cow::Borrowed(s))
+    }
+
+    /// Renders the rows of `#`/`.` pixels as a grid answer
+    pub fn grid<R: AsRef<[bool]>>(pixels: &[R]) -> Self {
+        Answer::Grid(
+            pixels
+                .iter()
+                .map(|row| {
+                    row.as_ref()
+                        .iter()
+                        .map(|&lit| if lit { '#' } else { '.' })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match n.to_i64() {
+            Some(n) => Answer::Int(n),
+            None => Answer::BigInt(n),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Int(n)
+    }
+}
+
+/// The other integer types go through `BigInt` when they don't fit in an `i64`
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Int(n),
+                        Err(_) => Answer::BigInt(BigInt::from(n)),
+                    }
+                }
+            }
+        )*
+    };
+}
+from_integer!(i32, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(Cow::Owned(s))
+    }
+}
+
+impl From<&'static str> for Answer {
+    fn from(s: &'static str) -> Self {
+        Answer::text(s)
+    }
+}
+
+/// Lets the tests compare to integer literals
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        matches!(self, Answer::Int(n) if n == other)
+    }
+}
+
+/// Lets the tests compare to string literals
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Text(s) => s == other,
+            Answer::Grid(rows) => rows.join("\n") == *other,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+    use num::BigInt;
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(7usize), 7);
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::BigInt(BigInt::from(u64::MAX))
+        );
+        assert_eq!(Answer::from(BigInt::from(42)), Answer::Int(42));
+        assert_eq!(Answer::from("ECFHLHZE".to_string()), "ECFHLHZE");
+        let grid = Answer::grid(&[[true, false], [false, true]]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!(grid, "#.\n.#");
+    }
+}
@@ -0,0 +1,195 @@
+use std::path::Path;
+
+use num::BigInt;
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::{DEFAULT_YEAR, PARTS};
+use crate::utils::read_challenge_data_path;
+
+/// Default file with the known answers
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Which input of a day an answer is for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputKind {
+    /// `dayNexample.txt`
+    Example,
+    /// `dayN.txt`
+    Real,
+}
+
+/// A known answer for one part of a day
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input: InputKind,
+    pub expected: Answer,
+}
+
+/// How an answer is written in the file.
+/// TOML integers are 64 bits so bigger numbers are written as strings
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Int(i64),
+    Text(String),
+}
+
+impl From<RawAnswer> for Answer {
+    fn from(raw: RawAnswer) -> Self {
+        match raw {
+            RawAnswer::Int(n) => Answer::Int(n),
+            // Several lines are a grid
+            RawAnswer::Text(s) if s.contains('\n') => {
+                Answer::Grid(s.lines().map(String::from).collect())
+            }
+            RawAnswer::Text(s) => match s.parse::<BigInt>() {
+                Ok(n) => Answer::from(n),
+                Err(_) => Answer::from(s),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawEntry {
+    /// Entries without a year are for the default year
+    #[serde(default = "default_year")]
+    year: u32,
+    day: u32,
+    part: u32,
+    input: InputKind,
+    expected: RawAnswer,
+}
+
+fn default_year() -> u32 {
+    DEFAULT_YEAR
+}
+
+#[derive(Deserialize)]
+struct RawFile {
+    #[serde(default)]
+    answer: Vec<RawEntry>,
+}
+
+/// Parses the content of an answers file.
+/// Checks that the parts exist and that no answer is given twice
+pub fn parse(path: &Path, content: &str) -> Result<Vec<Entry>, Error> {
+    let error = |message: String| Error::AnswersFile {
+        path: path.to_path_buf(),
+        message,
+    };
+    let raw: RawFile = toml::from_str(content).map_err(|e| error(e.to_string()))?;
+
+    let mut entries: Vec<Entry> = Vec::with_capacity(raw.answer.len());
+    for entry in raw.answer {
+        if !PARTS.contains(&entry.part) {
+            return Err(error(format!(
+                "day {} has no part {}",
+                entry.day, entry.part
+            )));
+        }
+        if find(&entries, entry.year, entry.day, entry.part, entry.input).is_some() {
+            return Err(error(format!(
+                "{} day {} part {} on the {:?} input is given twice",
+                entry.year, entry.day, entry.part, entry.input
+            )));
+        }
+        entries.push(Entry {
+            year: entry.year,
+            day: entry.day,
+            part: entry.part,
+            input: entry.input,
+            expected: Answer::from(entry.expected),
+        });
+    }
+    Ok(entries)
+}
+
+/// Reads and parses an answers file
+pub fn load(path: &Path) -> Result<Vec<Entry>, Error> {
+    let content = read_challenge_data_path(path.to_path_buf())?;
+    parse(path, &content)
+}
+
+/// The expected answer for a part of a day of the year, if there is one
+pub fn find(
+    entries: &[Entry],
+    year: u32,
+    day: u32,
+    part: u32,
+    input: InputKind,
+) -> Option<&Answer> {
+    entries
+        .iter()
+        .find(|e| e.year == year && e.day == day && e.part == part && e.input == input)
+        .map(|e| &e.expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, parse, InputKind};
+    use crate::answer::Answer;
+    use std::path::Path;
+    #[test]
+    fn test_parse() {
+        let path = Path::new("answers.toml");
+        let entries = parse(
+            path,
+            r##"answer = [
+                { day = 1, part = 1, input = "example", expected = 7 },
+                { day = 13, part = 2, input = "real", expected = "ECFHLHZE" },
+                { day = 13, part = 2, input = "example", expected = "#.\n.#" },
+                { day = 6, part = 2, input = "real", expected = "99999999999999999999" },
+                { year = 2022, day = 1, part = 1, input = "example", expected = 24000 },
+            ]"##,
+        )
+        .unwrap();
+        assert_eq!(
+            find(&entries, 2021, 1, 1, InputKind::Example),
+            Some(&Answer::Int(7))
+        );
+        assert_eq!(find(&entries, 2021, 1, 1, InputKind::Real), None);
+        assert_eq!(
+            find(&entries, 2022, 1, 1, InputKind::Example),
+            Some(&Answer::Int(24000))
+        );
+        assert_eq!(
+            find(&entries, 2021, 13, 2, InputKind::Real),
+            Some(&Answer::text("ECFHLHZE"))
+        );
+        assert_eq!(
+            find(&entries, 2021, 13, 2, InputKind::Example),
+            Some(&Answer::Grid(vec!["#.".to_string(), ".#".to_string()]))
+        );
+        assert!(matches!(
+            find(&entries, 2021, 6, 2, InputKind::Real),
+            Some(Answer::BigInt(_))
+        ));
+
+        assert!(parse(path, "").unwrap().is_empty());
+        assert!(parse(
+            path,
+            r#"answer = [{ day = 1, part = 3, input = "real", expected = 1 }]"#
+        )
+        .is_err());
+        assert!(parse(
+            path,
+            r#"answer = [{ day = 1, part = 1, input = "other", expected = 1 }]"#
+        )
+        .is_err());
+        assert!(parse(
+            path,
+            r#"answer = [
+                { day = 1, part = 1, input = "real", expected = 1 },
+                { day = 1, part = 1, input = "real", expected = 2 },
+            ]"#
+        )
+        .is_err());
+    }
+}
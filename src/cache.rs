@@ -0,0 +1,158 @@
+use std::{borrow::Cow, fs, path::PathBuf};
+
+use num::BigInt;
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::utils::write_file;
+use tracing::debug;
+
+/// Default directory with the cached answers
+pub const CACHE_DIR: &str = ".cache/answers";
+
+/// An answer as written in a cache file
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedAnswer {
+    Int(i64),
+    /// Written as decimal text, JSON numbers don't go that far
+    BigInt(String),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl From<&Answer> for CachedAnswer {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(n) => CachedAnswer::Int(*n),
+            Answer::BigInt(n) => CachedAnswer::BigInt(n.to_string()),
+            Answer::Text(s) => CachedAnswer::Text(s.to_string()),
+            Answer::Grid(rows) => CachedAnswer::Grid(rows.clone()),
+        }
+    }
+}
+
+impl TryFrom<CachedAnswer> for Answer {
+    type Error = num::bigint::ParseBigIntError;
+
+    fn try_from(cached: CachedAnswer) -> Result<Self, Self::Error> {
+        Ok(match cached {
+            CachedAnswer::Int(n) => Answer::Int(n),
+            CachedAnswer::BigInt(n) => Answer::from(n.parse::<BigInt>()?),
+            CachedAnswer::Text(s) => Answer::Text(Cow::Owned(s)),
+            CachedAnswer::Grid(rows) => Answer::Grid(rows),
+        })
+    }
+}
+
+/// Content of a cache file
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// `Solution::VERSION` of the solver that found the answer
+    version: u32,
+    answer: CachedAnswer,
+}
+
+/// Answers already found, kept on disk in `dir/YEAR/dayN/partP/<input SHA-256>.json`.
+/// An entry is only used if it was written by the same version of the solver,
+/// bumping `Solution::VERSION` invalidates the answers of the day
+#[derive(Clone, Debug)]
+pub struct AnswerCache {
+    dir: PathBuf,
+}
+
+impl Default for AnswerCache {
+    fn default() -> Self {
+        Self::new(CACHE_DIR)
+    }
+}
+
+impl AnswerCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Where the answer for the input is cached
+    pub fn path(&self, year: u32, day: u32, part: u32, input_sha256: &str) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{}", day))
+            .join(format!("part{}", part))
+            .join(format!("{}.json", input_sha256))
+    }
+
+    /// The cached answer, if there is one from this version of the solver.
+    /// Unreadable entries are ignored, they are overwritten by the next `put`
+    pub fn get(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        version: u32,
+        input_sha256: &str,
+    ) -> Option<Answer> {
+        let path = self.path(year, day, part, input_sha256);
+        let content = fs::read_to_string(&path).ok()?;
+        let entry: CacheEntry = match serde_json::from_str(&content) {
+            Ok(entry) => entry,
+            Err(e) => {
+                debug!(path = %path.display(), "ignoring the cached answer: {}", e);
+                return None;
+            }
+        };
+        if entry.version != version {
+            debug!(
+                cached = entry.version,
+                version, "the solver changed, ignoring the cached answer"
+            );
+            return None;
+        }
+        Answer::try_from(entry.answer).ok()
+    }
+
+    /// Caches the answer found by this version of the solver
+    pub fn put(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        version: u32,
+        input_sha256: &str,
+        answer: &Answer,
+    ) -> Result<(), Error> {
+        let entry = CacheEntry {
+            version,
+            answer: CachedAnswer::from(answer),
+        };
+        // Serializing these types can't fail
+        let content = serde_json::to_string(&entry).unwrap_or_default();
+        write_file(&self.path(year, day, part, input_sha256), &content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnswerCache;
+    use crate::answer::Answer;
+    use num::BigInt;
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = AnswerCache::new(dir.path());
+        assert_eq!(cache.get(2021, 22, 2, 1, "abc"), None);
+
+        let big = Answer::from(BigInt::from(u64::MAX) * 2);
+        cache.put(2021, 22, 2, 1, "abc", &big).unwrap();
+        assert_eq!(cache.get(2021, 22, 2, 1, "abc"), Some(big));
+        // Another input, another version of the solver or another year
+        assert_eq!(cache.get(2021, 22, 2, 1, "abd"), None);
+        assert_eq!(cache.get(2021, 22, 2, 2, "abc"), None);
+        assert_eq!(cache.get(2022, 22, 2, 1, "abc"), None);
+
+        let grid = Answer::Grid(vec!["#.".to_string(), ".#".to_string()]);
+        cache.put(2021, 22, 2, 2, "abc", &grid).unwrap();
+        assert_eq!(cache.get(2021, 22, 2, 2, "abc"), Some(grid));
+        assert_eq!(cache.get(2021, 22, 2, 1, "abc"), None);
+    }
+}
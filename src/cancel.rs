@@ -0,0 +1,74 @@
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::error::Error;
+
+/// Shared flag telling a running solution to stop.
+/// Cancelling is cooperative: long loops call `checkpoint` and return early
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    /// The token of the solution running on this thread
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the token checked by `checkpoint` on this thread
+pub fn with_token<T>(token: CancellationToken, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|current| current.replace(Some(token)));
+    let res = f();
+    CURRENT.with(|current| *current.borrow_mut() = previous);
+    res
+}
+
+/// Returns an error if the solution running on this thread was cancelled.
+/// Without a token, like when a day is used as a library, it never fails
+pub fn checkpoint(day: u32) -> Result<(), Error> {
+    let cancelled = CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    });
+    if cancelled {
+        Err(Error::Cancelled { day })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checkpoint, with_token, CancellationToken};
+    #[test]
+    fn test_checkpoint() {
+        assert!(checkpoint(1).is_ok());
+        let token = CancellationToken::new();
+        with_token(token.clone(), || {
+            assert!(checkpoint(1).is_ok());
+            token.cancel();
+            assert!(checkpoint(1).is_err());
+        });
+        // The token only applies inside `with_token`
+        assert!(checkpoint(1).is_ok());
+    }
+}
@@ -0,0 +1,153 @@
+use std::{fmt, io, path::PathBuf};
+
+/// Crate wide error type
+#[derive(Debug)]
+pub enum Error {
+    /// The input couldn't be read
+    Io { path: PathBuf, source: io::Error },
+    /// Malformed input. `line` and `column` start from 1
+    Parse {
+        day: u32,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input was parsed but the puzzle can't be solved with it
+    InvalidState { day: u32, message: String },
+    /// The solution was stopped before finishing, usually because it ran out of time
+    Cancelled { day: u32 },
+    /// Letters drawn in pixels couldn't be read. `index` is the position of the glyph in the text,
+    /// `column` the column where it starts, from 1, and `glyph` its rows separated by `/`
+    UnknownGlyph {
+        index: usize,
+        column: usize,
+        glyph: String,
+    },
+    /// The input couldn't be downloaded
+    Fetch { url: String, message: String },
+    /// The file with the known answers is malformed
+    AnswersFile { path: PathBuf, message: String },
+    /// A new day couldn't be generated, nothing is overwritten
+    Scaffold { path: PathBuf, message: String },
+    /// There is no registered solution for the day and part of the year.
+    /// `available` lists the registered solutions
+    UnknownSolution {
+        year: u32,
+        day: u32,
+        part: u32,
+        available: String,
+    },
+}
+
+impl Error {
+    pub fn parse(day: u32, line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            day,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid(day: u32, message: impl Into<String>) -> Self {
+        Error::InvalidState {
+            day,
+            message: message.into(),
+        }
+    }
+
+    /// Moves a parse error to `line`, for errors of a single line parsed with `FromStr`
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            Error::Parse {
+                day,
+                column,
+                message,
+                ..
+            } => Error::Parse {
+                day,
+                line,
+                column,
+                message,
+            },
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "Couldn't read {}: {}", path.display(), source),
+            Error::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Day {}: parse error at line {}, column {}: {}",
+                day, line, column, message
+            ),
+            Error::InvalidState { day, message } => write!(f, "Day {}: {}", day, message),
+            Error::Cancelled { day } => write!(f, "Day {}: cancelled", day),
+            Error::UnknownGlyph {
+                index,
+                column,
+                glyph,
+            } => write!(f, "Unknown glyph {} at column {}: {}", index, column, glyph),
+            Error::Fetch { url, message } => write!(f, "Couldn't download {}: {}", url, message),
+            Error::AnswersFile { path, message } => {
+                write!(f, "Invalid answers file {}: {}", path.display(), message)
+            }
+            Error::Scaffold { path, message } => {
+                write!(f, "Couldn't scaffold {}: {}", path.display(), message)
+            }
+            Error::UnknownSolution {
+                year,
+                day,
+                part,
+                available,
+            } => write!(
+                f,
+                "No solution for {} day {} part {}. Available solutions:\n{}",
+                year, day, part, available
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Column of `token` inside `line`, starting from 1.
+/// `token` should be a subslice of `line`, otherwise returns 1
+pub fn column(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let t = token.as_ptr() as usize;
+    if t < start || t > start + line.len() {
+        return 1;
+    }
+    line.get(..t - start)
+        .map(|s| s.chars().count() + 1)
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::column;
+    #[test]
+    fn test_column() {
+        let line = "forward 5";
+        assert_eq!(column(line, &line[8..]), 9);
+        assert_eq!(column(line, line), 1);
+        let other = String::from("other");
+        assert_eq!(column(line, &other), 1);
+    }
+}
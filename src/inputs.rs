@@ -0,0 +1,198 @@
+use std::{env, fs, io::ErrorKind, path::PathBuf};
+
+use crate::error::Error;
+use crate::utils::write_file;
+
+/// Where the inputs are downloaded from
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Default directory with the downloaded inputs
+pub const CACHE_DIR: &str = ".cache/inputs";
+/// Account used when none is given
+pub const DEFAULT_ACCOUNT: &str = "default";
+
+/// Makes the requests for `Fetcher`, so tests can answer them without the network
+pub trait HttpClient {
+    /// GET `url` with the session cookie. Returns the body or why the request failed
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+}
+
+/// `HttpClient` doing real requests
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UreqClient;
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", session))
+            // AoC asks automated tools to say who they are
+            .set(
+                "User-Agent",
+                concat!("advent_of_rust/", env!("CARGO_PKG_VERSION")),
+            )
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(code, _) => format!("the server answered {}", code),
+                e => e.to_string(),
+            })?;
+        response.into_string().map_err(|e| e.to_string())
+    }
+}
+
+/// Downloads the inputs and keeps them in `cache_dir/account/year/dayN.txt`.
+/// An input is downloaded only once, after that it's read from the cache,
+/// which also works without a network or a session token
+#[derive(Clone, Debug)]
+pub struct Fetcher<C = UreqClient> {
+    client: C,
+    base_url: String,
+    cache_dir: PathBuf,
+    account: String,
+    session: Option<String>,
+}
+
+impl Fetcher<UreqClient> {
+    /// Configured from the environment:
+    /// `AOC_SESSION` is the session cookie of the account,
+    /// `AOC_ACCOUNT` the name of its directory in the cache and
+    /// `AOC_CACHE_DIR` the cache directory
+    pub fn from_env() -> Self {
+        let mut fetcher = Fetcher::new(
+            UreqClient,
+            env::var_os("AOC_CACHE_DIR").map_or_else(|| PathBuf::from(CACHE_DIR), PathBuf::from),
+        );
+        if let Ok(account) = env::var("AOC_ACCOUNT") {
+            fetcher = fetcher.account(account);
+        }
+        if let Ok(session) = env::var("AOC_SESSION") {
+            fetcher = fetcher.session(session.trim());
+        }
+        fetcher
+    }
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            client,
+            base_url: BASE_URL.to_string(),
+            cache_dir: cache_dir.into(),
+            account: DEFAULT_ACCOUNT.to_string(),
+            session: None,
+        }
+    }
+
+    /// Downloads from another server, like a local one in tests
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Name of the account, each account has its own inputs
+    pub fn account(mut self, account: impl Into<String>) -> Self {
+        self.account = account.into();
+        self
+    }
+
+    /// Session cookie of the account, needed to download
+    pub fn session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    pub fn has_session(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Where the input of the day is cached
+    pub fn cache_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_dir
+            .join(&self.account)
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    pub fn is_cached(&self, year: u32, day: u32) -> bool {
+        self.cache_path(year, day).is_file()
+    }
+
+    /// The input of the day, from the cache or downloaded and then cached
+    pub fn input(&self, year: u32, day: u32) -> Result<String, Error> {
+        let path = self.cache_path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() == ErrorKind::NotFound => (),
+            Err(source) => return Err(Error::Io { path, source }),
+        }
+
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let session = self.session.as_deref().ok_or_else(|| Error::Fetch {
+            url: url.clone(),
+            message: "there is no session token, set AOC_SESSION".to_string(),
+        })?;
+        let input = self
+            .client
+            .get(&url, session)
+            .map_err(|message| Error::Fetch { url, message })?;
+
+        write_file(&path, &input)?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fetcher, HttpClient};
+    use std::cell::Cell;
+
+    /// Answers every request with the URL and counts them
+    struct Stub {
+        calls: Cell<usize>,
+    }
+
+    impl HttpClient for &Stub {
+        fn get(&self, url: &str, session: &str) -> Result<String, String> {
+            self.calls.set(self.calls.get() + 1);
+            match session {
+                "good" => Ok(format!("{}\n", url)),
+                _ => Err("the server answered 400".to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_fetcher() {
+        let dir = tempfile::tempdir().unwrap();
+        let stub = Stub {
+            calls: Cell::new(0),
+        };
+        let fetcher = Fetcher::new(&stub, dir.path()).base_url("http://stub/");
+
+        // Nothing cached and no session
+        assert!(fetcher.input(2021, 1).is_err());
+        assert_eq!(stub.calls.get(), 0);
+
+        let fetcher = fetcher.account("me").session("good");
+        assert_eq!(
+            fetcher.input(2021, 1).unwrap(),
+            "http://stub/2021/day/1/input\n"
+        );
+        assert_eq!(
+            fetcher.input(2021, 1).unwrap(),
+            "http://stub/2021/day/1/input\n"
+        );
+        assert_eq!(stub.calls.get(), 1);
+        assert!(fetcher.cache_path(2021, 1).ends_with("me/2021/day1.txt"));
+
+        // Another account has its own inputs
+        let other = Fetcher::new(&stub, dir.path())
+            .account("other")
+            .session("bad");
+        assert!(other.input(2021, 1).is_err());
+        assert!(!other.is_cached(2021, 1));
+    }
+}
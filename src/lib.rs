@@ -0,0 +1,25 @@
+//! Solutions to [advent of code](https://adventofcode.com), one module per year.
+//!
+//! Each `yearYYYY::dayN` module has a unit struct `DayN` implementing [`Solution`]:
+//! `DayN::parse` turns the raw input into the day's `Input` type and
+//! `DayN::part1`/`DayN::part2` solve it.
+//! The days of a year are registered in its `SOLUTIONS` and the years in [`YEARS`].
+
+pub mod answer;
+pub mod answers;
+pub mod cache;
+pub mod cancel;
+pub mod error;
+pub mod inputs;
+pub mod ocr;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod utils;
+pub mod watch;
+pub mod year2021;
+
+pub use answer::Answer;
+pub use error::Error;
+pub use solution::{Challenge, Solution, YEARS};
@@ -1,91 +1,91 @@
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod utils;
+use advent_of_rust::{
+    answers::{self, ANSWERS_FILE},
+    cache::AnswerCache,
+    report::{self, Format},
+    runner,
+    scaffold::Layout,
+    solution, utils,
+    utils::InputSource,
+    watch::{self, Watcher},
+    Answer, Error,
+};
+use clap::{App, Arg, SubCommand};
+use std::{
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tracing::{debug, info, warn, Level};
 
-use clap::{App, Arg};
+/// Finds the solution for the day of the year and runs the part on the input.
+/// With a cache, an answer already found for the same input by the same solver is reused
+fn challenge(
+    year: u32,
+    day: u32,
+    part: u32,
+    source: &InputSource,
+    cache: Option<&AnswerCache>,
+) -> Result<Answer, Error> {
+    let challenge = solution::find(year, day, part)?;
+    let input = source.read(year, day)?;
+    let cache = match cache {
+        Some(cache) => cache,
+        None => return challenge.run(&input, part == 1),
+    };
+    let input_sha256 = utils::sha256_hex(input.as_bytes());
+    if let Some(answer) = cache.get(year, day, part, challenge.version(), &input_sha256) {
+        debug!("cached answer");
+        return Ok(answer);
+    }
+    let answer = challenge.run(&input, part == 1)?;
+    if let Err(e) = cache.put(year, day, part, challenge.version(), &input_sha256, &answer) {
+        warn!("couldn't cache the answer: {}", e);
+    }
+    Ok(answer)
+}
 
+/// Runs the part again every time its input file changes.
+/// The first answer is printed, then the differences with the previous answer
+fn watch(year: u32, day: u32, part: u32, source: &InputSource, path: PathBuf) -> ! {
+    let mut watcher = Watcher::new([path.clone()]);
+    let mut previous: Option<Answer> = None;
+    loop {
+        match challenge(year, day, part, source, None) {
+            Ok(answer) => {
+                match &previous {
+                    Some(previous) => print!("{}", watch::diff(previous, &answer)),
+                    None => println!("{}", answer),
+                }
+                previous = Some(answer);
+            }
+            Err(e) => eprintln!("{}", e),
+        }
+        info!("watching {} for changes", path.display());
+        watcher.wait(watch::POLL_INTERVAL);
+    }
+}
 
-fn challenge(day: u32, part: u32, run_example: bool) {
-    match (day, part) {
-        (1, 1) => day1::solve(run_example, true),
-        (1, 2) => day1::solve(run_example, false),
-        (2, 1) => day2::solve(run_example, true),
-        (2, 2) => day2::solve(run_example, false),
-        (3, 1) => day3::solve(run_example, true),
-        (3, 2) => day3::solve(run_example, false),
-        (4, 1) => day4::solve(run_example, true),
-        (4, 2) => day4::solve(run_example, false),
-        (5, 1) => day5::solve(run_example, true),
-        (5, 2) => day5::solve(run_example, false),
-        (6, 1) => day6::solve(run_example, true),
-        (6, 2) => day6::solve(run_example, false),
-        (7, 1) => day7::solve(run_example, true),
-        (7, 2) => day7::solve(run_example, false),
-        (8, 1) => day8::solve(run_example, true),
-        (8, 2) => day8::solve(run_example, false),
-        (9, 1) => day9::solve(run_example, true),
-        (9, 2) => day9::solve(run_example, false),
-        (10, 1) => day10::solve(run_example, true),
-        (10, 2) => day10::solve(run_example, false),
-        (11, 1) => day11::solve(run_example, true),
-        (11, 2) => day11::solve(run_example, false),
-        (12, 1) => day12::solve(run_example, true),
-        (12, 2) => day12::solve(run_example, false),
-        (13, 1) => day13::solve(run_example, true),
-        (13, 2) => day13::solve(run_example, false),
-        (14, 1) => day14::solve(run_example, true),
-        (14, 2) => day14::solve(run_example, false),
-        (15, 1) => day15::solve(run_example, true),
-        (15, 2) => day15::solve(run_example, false),
-        (16, 1) => day16::solve(run_example, true),
-        (16, 2) => day16::solve(run_example, false),
-        (17, 1) => day17::solve(run_example, true),
-        (17, 2) => day17::solve(run_example, false),
-        (18, 1) => day18::solve(run_example, true),
-        (18, 2) => day18::solve(run_example, false),
-        (19, 1) => day19::solve(run_example, true),
-        (19, 2) => day19::solve(run_example, false),
-        (20, 1) => day20::solve(run_example, true),
-        (20, 2) => day20::solve(run_example, false),
-        (21, 1) => day21::solve(run_example, true),
-        (21, 2) => day21::solve(run_example, false),
-        (22, 1) => day22::solve(run_example, true),
-        (22, 2) => day22::solve(run_example, false),
-        (23, 1) => day23::solve(run_example, true),
-        (23, 2) => day23::solve(run_example, false),
-        (24, 1) => day24::solve(run_example, true),
-        (24, 2) => day24::solve(run_example, false),
-        (25, 1) => day25::solve(run_example, true),
-        (25, 2) => day25::solve(run_example, false),
-        _ => 0,
-    };
+/// If a record should make the process exit with an error
+fn failed(records: &[runner::Record]) -> bool {
+    records.iter().any(|r| {
+        matches!(
+            r.status,
+            runner::Status::Error(_) | runner::Status::Panic(_) | runner::Status::Timeout
+        )
+    })
+}
+
+/// The known answers the records are checked against.
+/// Without a readable `answers.toml` every status is unknown
+fn known_answers() -> Vec<answers::Entry> {
+    answers::load(Path::new(ANSWERS_FILE)).unwrap_or_else(|e| {
+        warn!("{}", e);
+        Vec::new()
+    })
 }
 
 fn main() {
-    let matches = App::new("Advent of Rust 2021")
+    let matches = App::new("Advent of Rust")
         .author("Zademn")
         .arg(
             Arg::with_name("day")
@@ -97,16 +97,291 @@ fn main() {
                 .help("Part of the challenge: 1 or 2")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("year")
+                .short("y")
+                .long("year")
+                .takes_value(true)
+                .global(true)
+                .help("Year of the challenge [default: 2021]"),
+        )
         .arg(
             Arg::with_name("example")
                 .short("e")
+                .global(true)
                 .help("If the example should be run"),
         )
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .takes_value(true)
+                .conflicts_with("example")
+                .help("Read the input from this file instead of resources. Use `-` for stdin"),
+        )
+        .arg(
+            Arg::with_name("resources")
+                .long("resources")
+                .takes_value(true)
+                .global(true)
+                .default_value(utils::RESOURCES)
+                .help(
+                    "Directory with the dayN.txt and dayNexample.txt files, in a YEAR directory for other years than 2021. \
+                     Missing inputs are only downloaded for the default resources directory",
+                ),
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .takes_value(true)
+                .global(true)
+                .default_value("text")
+                .help("Output format: text, json or csv. json and csv have one record for each part"),
+        )
+        .arg(
+            Arg::with_name("verbose")
+                .short("v")
+                .multiple(true)
+                .global(true)
+                .help("Print the debug messages of the solutions, -vv for trace messages"),
+        )
+        .arg(
+            Arg::with_name("quiet")
+                .short("q")
+                .long("quiet")
+                .global(true)
+                .conflicts_with("verbose")
+                .help("Only print errors"),
+        )
+        .arg(
+            Arg::with_name("watch")
+                .short("w")
+                .long("watch")
+                .conflicts_with("list")
+                .help("Run the part again when its input file changes and show how the answer changed"),
+        )
+        .arg(
+            Arg::with_name("no-cache")
+                .long("no-cache")
+                .help(
+                    "Always solve, don't reuse or save the answer in the answers cache. \
+                     json and csv always solve, their records time the solution",
+                ),
+        )
+        .arg(
+            Arg::with_name("list")
+                .short("l")
+                .long("list")
+                .help("List the available solutions"),
+        )
+        .subcommand(
+            SubCommand::with_name("run-all")
+                .about("Run both parts of every day of the year and print a table with the results")
+                .arg(
+                    Arg::with_name("jobs")
+                        .short("j")
+                        .long("jobs")
+                        .takes_value(true)
+                        .help("Number of days solved at the same time [default: number of CPUs]"),
+                )
+                .arg(
+                    Arg::with_name("timeout")
+                        .long("timeout")
+                        .takes_value(true)
+                        .help(
+                            "Time budget in seconds for each part, slower parts are reported as TIMEOUT. \
+                             Only the days checking for cancellation (15, 17, 19, 22, 24) stop there, \
+                             the others keep running until they finish",
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("new")
+                .about("Write the module of a new day from the template, register it and create its empty inputs")
+                .arg(
+                    Arg::with_name("new-day")
+                        .value_name("day")
+                        .help("Day to create")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("title")
+                        .long("title")
+                        .takes_value(true)
+                        .help("Title of the puzzle [default: TODO]"),
+                ),
+        )
         .get_matches();
 
-    let day = matches.value_of("day").unwrap().parse().unwrap();
-    let part = matches.value_of("part").unwrap().parse().unwrap();
-    let run_example = matches.is_present("example");
-    //challenge!(day.as_str(), part.as_str());
-    challenge(day, part, run_example);
+    if matches.is_present("list") {
+        print!("{}", solution::list());
+        return;
+    }
+
+    // The solutions log on stderr, stdout only has the answers
+    let level = match (
+        matches.is_present("quiet"),
+        matches.occurrences_of("verbose"),
+    ) {
+        (true, _) => Level::ERROR,
+        (false, 0) => Level::INFO,
+        (false, 1) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_target(false)
+        .without_time()
+        .init();
+
+    let format: Format = match matches.value_of("format").unwrap().parse() {
+        Ok(format) => format,
+        Err(e) => {
+            eprintln!("{}\n{}", e, matches.usage());
+            std::process::exit(1);
+        }
+    };
+
+    let year = match matches.value_of("year").map(str::parse) {
+        None => solution::DEFAULT_YEAR,
+        Some(Ok(year)) => year,
+        Some(Err(_)) => {
+            eprintln!("--year must be a number\n{}", matches.usage());
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(sub_matches) = matches.subcommand_matches("new") {
+        let day = match sub_matches.value_of("new-day").unwrap().parse() {
+            Ok(day) => day,
+            Err(_) => {
+                eprintln!("<day> must be a number\n{}", sub_matches.usage());
+                std::process::exit(1);
+            }
+        };
+        let layout = Layout {
+            resources: PathBuf::from(sub_matches.value_of("resources").unwrap()),
+            ..Layout::default()
+        };
+        let title = sub_matches.value_of("title").unwrap_or("TODO");
+        match layout.new_day(year, day, title) {
+            Ok(written) => {
+                for path in written {
+                    println!("wrote {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(sub_matches) = matches.subcommand_matches("run-all") {
+        let source = InputSource::Resources {
+            dir: PathBuf::from(sub_matches.value_of("resources").unwrap()),
+            example: sub_matches.is_present("example"),
+        };
+        let jobs = match sub_matches.value_of("jobs").map(str::parse) {
+            None => runner::default_jobs(),
+            Some(Ok(jobs)) if jobs > 0 => jobs,
+            Some(_) => {
+                eprintln!("--jobs must be a positive number\n{}", sub_matches.usage());
+                std::process::exit(1);
+            }
+        };
+        let timeout = match sub_matches.value_of("timeout").map(str::parse::<f64>) {
+            None => None,
+            Some(Ok(seconds)) if seconds > 0.0 && seconds.is_finite() => {
+                Some(Duration::from_secs_f64(seconds))
+            }
+            Some(_) => {
+                eprintln!(
+                    "--timeout must be a positive number of seconds\n{}",
+                    sub_matches.usage()
+                );
+                std::process::exit(1);
+            }
+        };
+        let records = runner::run_all(&source, &known_answers(), year, jobs, timeout);
+        print!("{}", report::render(&records, format));
+        if failed(&records) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let (day, part) = match (matches.value_of("day"), matches.value_of("part")) {
+        (Some(day), Some(part)) => (day, part),
+        _ => {
+            eprintln!("Missing <day> and <part>\n{}", matches.usage());
+            std::process::exit(1);
+        }
+    };
+    let (day, part) = match (day.parse(), part.parse()) {
+        (Ok(day), Ok(part)) => (day, part),
+        _ => {
+            eprintln!("<day> and <part> must be numbers\n{}", matches.usage());
+            std::process::exit(1);
+        }
+    };
+    let source = match matches.value_of("input") {
+        Some(path) => InputSource::Path(PathBuf::from(path)),
+        None => InputSource::Resources {
+            dir: PathBuf::from(matches.value_of("resources").unwrap()),
+            example: matches.is_present("example"),
+        },
+    };
+    if matches.is_present("watch") {
+        if format != Format::Text {
+            eprintln!("--watch only prints text\n{}", matches.usage());
+            std::process::exit(1);
+        }
+        let path = match source.path(year, day) {
+            Some(path) => path,
+            None => {
+                eprintln!("stdin can't be watched, give a file with --input");
+                std::process::exit(1);
+            }
+        };
+        if let Err(e) = solution::find(year, day, part) {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        watch(year, day, part, &source, path);
+    }
+    // The records time the solution, a cached answer would have no duration:
+    // json and csv skip the answers cache like `--no-cache`
+    if format != Format::Text {
+        let challenge = match solution::find(year, day, part) {
+            Ok(challenge) => challenge,
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        };
+        let records = [runner::run(
+            challenge,
+            part,
+            &source,
+            &known_answers(),
+            None,
+        )];
+        print!("{}", report::render(&records, format));
+        if failed(&records) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let cache = (!matches.is_present("no-cache")).then(AnswerCache::default);
+    match challenge(year, day, part, &source, cache.as_ref()) {
+        Ok(answer) => println!("{}", answer),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
 }
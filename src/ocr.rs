@@ -0,0 +1,109 @@
+use crate::error::Error;
+
+/// Height in pixels of the AoC block letters
+pub const HEIGHT: usize = 6;
+
+/// The letters seen in AoC puzzles so far, most of them are 4 pixels wide
+static ALPHABET: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters drawn by the lit pixels.
+/// Letters are separated by columns without lit pixels.
+/// The error tells which glyph wasn't recognised and the column it starts at
+pub fn read<R: AsRef<[bool]>>(pixels: &[R]) -> Result<String, Error> {
+    let width = pixels
+        .iter()
+        .map(|row| row.as_ref().len())
+        .max()
+        .unwrap_or(0);
+    let lit = |i: usize, j: usize| pixels[i].as_ref().get(j).copied().unwrap_or(false);
+    let empty_column = |j: usize| (0..pixels.len()).all(|i| !lit(i, j));
+
+    let mut text = String::new();
+    let mut j = 0;
+    while j < width {
+        if empty_column(j) {
+            j += 1;
+            continue;
+        }
+        let start = j;
+        while j < width && !empty_column(j) {
+            j += 1;
+        }
+        let glyph: Vec<String> = (0..pixels.len())
+            .map(|i| {
+                (start..j)
+                    .map(|j| if lit(i, j) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let letter = ALPHABET
+            .iter()
+            .find(|(_, pattern)| pattern.iter().eq(glyph.iter()))
+            .map(|&(letter, _)| letter)
+            .ok_or_else(|| Error::UnknownGlyph {
+                index: text.chars().count(),
+                column: start + 1,
+                glyph: glyph.join("/"),
+            })?;
+        text.push(letter);
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, ALPHABET, HEIGHT};
+    use crate::error::Error;
+
+    /// Draws the text the way AoC does, one empty column between letters
+    fn draw(text: &str) -> Vec<Vec<bool>> {
+        let mut pixels = vec![Vec::new(); HEIGHT];
+        for c in text.chars() {
+            let (_, pattern) = ALPHABET.iter().find(|(letter, _)| *letter == c).unwrap();
+            for (row, line) in pixels.iter_mut().zip(pattern) {
+                row.extend(line.chars().map(|p| p == '#'));
+                row.push(false);
+            }
+        }
+        pixels
+    }
+
+    #[test]
+    fn test_read() {
+        let alphabet: String = ALPHABET.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(read(&draw(&alphabet)).unwrap(), alphabet);
+        assert_eq!(read(&draw("ECFHLHZE")).unwrap(), "ECFHLHZE");
+
+        // A square after "HI"
+        let mut pixels = draw("HI");
+        for (i, row) in pixels.iter_mut().enumerate() {
+            let edge = i == 0 || i == HEIGHT - 1;
+            row.extend([true, edge, edge, true]);
+        }
+        match read(&pixels) {
+            Err(Error::UnknownGlyph { index, column, .. }) => {
+                assert_eq!((index, column), (2, 10));
+            }
+            other => panic!("expected an unknown glyph, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::{str::FromStr, time::Duration};
+
+use serde::Serialize;
+
+use crate::{
+    answer::Answer,
+    runner::{Record, Status},
+    utils,
+};
+
+/// How the records are written
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// A table for humans
+    Text,
+    /// An array with an object for each record
+    Json,
+    /// A header and a row for each record
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format {:?}, expected json, csv or text",
+                s
+            )),
+        }
+    }
+}
+
+/// Writes the records in the format
+pub fn render(records: &[Record], format: Format) -> String {
+    match format {
+        Format::Text => table(records),
+        Format::Json => json(records),
+        Format::Csv => csv(records),
+    }
+}
+
+/// Name of the status, the expected answer for failures and the message for errors
+fn status_fields(status: &Status) -> (&'static str, Option<&Answer>, Option<&str>) {
+    match status {
+        Status::Pass => ("pass", None, None),
+        Status::Fail { expected } => ("fail", Some(expected), None),
+        Status::Unknown => ("unknown", None, None),
+        Status::Error(msg) => ("error", None, Some(msg)),
+        Status::Panic(msg) => ("panic", None, Some(msg)),
+        Status::Timeout => ("timeout", None, None),
+    }
+}
+
+/// Milliseconds with microsecond precision, easier to plot than a `Duration`
+fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+/// Integers that fit are JSON numbers, everything else is written as text.
+/// Grid rows are separated by newlines
+#[derive(Serialize)]
+#[serde(untagged)]
+enum JsonAnswer {
+    Int(i64),
+    Text(String),
+}
+
+impl From<&Answer> for JsonAnswer {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(n) => JsonAnswer::Int(*n),
+            answer => JsonAnswer::Text(answer.to_string()),
+        }
+    }
+}
+
+/// A record as written in JSON, the fields keep this order
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: Option<JsonAnswer>,
+    duration_ms: f64,
+    input_sha256: Option<&'a str>,
+    status: &'static str,
+    expected: Option<JsonAnswer>,
+    message: Option<&'a str>,
+}
+
+/// Formats the records as a JSON array
+pub fn json(records: &[Record]) -> String {
+    let records: Vec<JsonRecord> = records
+        .iter()
+        .map(|r| {
+            let (status, expected, message) = status_fields(&r.status);
+            JsonRecord {
+                year: r.year,
+                day: r.day,
+                part: r.part,
+                answer: r.answer.as_ref().map(JsonAnswer::from),
+                duration_ms: millis(r.duration),
+                input_sha256: r.input_sha256.as_deref(),
+                status,
+                expected: expected.map(JsonAnswer::from),
+                message,
+            }
+        })
+        .collect();
+    let mut s = serde_json::to_string_pretty(&records).unwrap_or_default();
+    s.push('\n');
+    s
+}
+
+/// Formats the records as CSV with a header. Empty fields are missing values
+pub fn csv(records: &[Record]) -> String {
+    let header = [
+        "year",
+        "day",
+        "part",
+        "answer",
+        "duration_ms",
+        "input_sha256",
+        "status",
+        "expected",
+        "message",
+    ];
+    let rows = records.iter().map(|r| {
+        let (status, expected, message) = status_fields(&r.status);
+        [
+            r.year.to_string(),
+            r.day.to_string(),
+            r.part.to_string(),
+            r.answer.as_ref().map(Answer::to_string).unwrap_or_default(),
+            millis(r.duration).to_string(),
+            r.input_sha256.clone().unwrap_or_default(),
+            status.to_string(),
+            expected.map(Answer::to_string).unwrap_or_default(),
+            message.unwrap_or_default().to_string(),
+        ]
+    });
+    utils::csv_string(&header, rows)
+}
+
+/// Formats the records as a table with the answer, time and status of each part.
+/// Grids don't fit in a cell, they are drawn under their row
+pub fn table(records: &[Record]) -> String {
+    let mut s = format!(
+        "{:>3} {:>4} {:>20} {:>12}  {}\n",
+        "Day", "Part", "Answer", "Time", "Status"
+    );
+    for r in records {
+        let answer = match &r.answer {
+            Some(Answer::Grid(rows)) => format!(
+                "{}x{} grid",
+                rows.first().map_or(0, |row| row.chars().count()),
+                rows.len()
+            ),
+            Some(answer) => answer.to_string(),
+            None => String::new(),
+        };
+        let status = match &r.status {
+            Status::Pass => "ok".to_string(),
+            Status::Fail {
+                expected: Answer::Grid(_),
+            } => "FAIL (expected another grid)".to_string(),
+            Status::Fail { expected } => format!("FAIL (expected {})", expected),
+            Status::Unknown => "?".to_string(),
+            Status::Error(msg) => format!("ERROR ({})", msg),
+            Status::Panic(msg) => format!("PANIC ({})", msg),
+            Status::Timeout => "TIMEOUT".to_string(),
+        };
+        s += &format!(
+            "{:>3} {:>4} {:>20} {:>12}  {}\n",
+            r.day,
+            r.part,
+            answer,
+            format!("{:.2?}", r.duration),
+            status
+        );
+        if let Some(Answer::Grid(rows)) = &r.answer {
+            for row in rows {
+                s += &format!("{:>9}{}\n", "", row);
+            }
+        }
+    }
+    let passed = records.iter().filter(|r| r.status == Status::Pass).count();
+    let total: Duration = records.iter().map(|r| r.duration).sum();
+    s += &format!("{}/{} passed in {:.2?}\n", passed, records.len(), total);
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv, json, Format};
+    use crate::answer::Answer;
+    use crate::runner::{Record, Status};
+    use std::time::Duration;
+    #[test]
+    fn test_formats() {
+        let records = [
+            Record {
+                year: 2021,
+                day: 1,
+                part: 1,
+                answer: Some(Answer::Int(7)),
+                duration: Duration::from_micros(1500),
+                status: Status::Pass,
+                input_sha256: Some("abc".to_string()),
+            },
+            Record {
+                year: 2021,
+                day: 13,
+                part: 2,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::Error("no, \"really\"".to_string()),
+                input_sha256: None,
+            },
+        ];
+        let value: serde_json::Value = serde_json::from_str(&json(&records)).unwrap();
+        assert_eq!(value[0]["year"], 2021);
+        assert_eq!(value[0]["answer"], 7);
+        assert_eq!(value[0]["duration_ms"], 1.5);
+        assert_eq!(value[0]["status"], "pass");
+        assert_eq!(value[1]["message"], "no, \"really\"");
+        assert!(value[1]["input_sha256"].is_null());
+
+        let csv = csv(&records);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("year,day,part,answer,duration_ms,input_sha256,status,expected,message")
+        );
+        assert_eq!(lines.next(), Some("2021,1,1,7,1.5,abc,pass,,"));
+        assert_eq!(
+            lines.next(),
+            Some("2021,13,2,,0,,error,,\"no, \"\"really\"\"\"")
+        );
+
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    answers::{self, Entry},
+    cancel::{self, CancellationToken},
+    error::Error,
+    solution::{self, Challenge, PARTS},
+    utils::{self, InputSource},
+};
+
+/// Outcome of running a solution compared to its known answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: Answer,
+    },
+    /// There is no known answer to compare to
+    Unknown,
+    /// The input couldn't be read or solved, keeps the error message
+    Error(String),
+    /// The solution panicked, keeps the panic message
+    Panic(String),
+    /// The solution didn't finish in the time budget
+    Timeout,
+}
+
+/// The result of running one part of a day
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status,
+    /// SHA-256 of the input, `None` if it couldn't be read
+    pub input_sha256: Option<String>,
+}
+
+/// Gets the message out of a panic payload
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Reads the input and runs one part of a challenge.
+/// The answer is checked against the `answers` loaded from `answers.toml`.
+/// Panics are caught and reported in the status.
+/// The duration covers parsing and solving, not reading the input.
+/// The part runs on its own thread. After `timeout` the runner stops waiting for it
+/// and cancels it, the thread stops at its next `cancel::checkpoint`.
+/// A solution without checkpoints keeps its thread busy until it finishes
+pub fn run(
+    challenge: &'static dyn Challenge,
+    part: u32,
+    source: &InputSource,
+    answers: &[Entry],
+    timeout: Option<Duration>,
+) -> Record {
+    let part1 = part == 1;
+    let input = match source.read(challenge.year(), challenge.day()) {
+        Ok(input) => input,
+        Err(e) => {
+            return Record {
+                year: challenge.year(),
+                day: challenge.day(),
+                part,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::Error(e.to_string()),
+                input_sha256: None,
+            }
+        }
+    };
+    let input_sha256 = Some(utils::sha256_hex(input.as_bytes()));
+    let record = |answer, duration, status| Record {
+        year: challenge.year(),
+        day: challenge.day(),
+        part,
+        answer,
+        duration,
+        status,
+        input_sha256,
+    };
+
+    let token = CancellationToken::new();
+    let (sender, receiver) = mpsc::channel();
+    let thread_token = token.clone();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            cancel::with_token(thread_token, || challenge.run(&input, part1))
+        }));
+        // Nobody is listening anymore if the part timed out
+        let _ = sender.send((result, start.elapsed()));
+    });
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).ok(),
+        None => receiver.recv().ok(),
+    };
+
+    let (result, duration) = match received {
+        Some(received) => received,
+        None => {
+            token.cancel();
+            return record(None, timeout.unwrap_or_default(), Status::Timeout);
+        }
+    };
+    let (answer, status) = match result {
+        Ok(Ok(answer)) => {
+            let expected = source.known_answers().and_then(|input| {
+                answers::find(answers, challenge.year(), challenge.day(), part, input).cloned()
+            });
+            let status = match expected {
+                Some(expected) if expected == answer => Status::Pass,
+                Some(expected) => Status::Fail { expected },
+                None => Status::Unknown,
+            };
+            (Some(answer), status)
+        }
+        // Only the runner cancels, so a cancelled part ran out of time
+        Ok(Err(Error::Cancelled { .. })) => (None, Status::Timeout),
+        Ok(Err(e)) => (None, Status::Error(e.to_string())),
+        Err(payload) => (None, Status::Panic(panic_message(payload))),
+    };
+    record(answer, duration, status)
+}
+
+/// Number of threads used by default: one per available CPU
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Runs both parts of every registered solution of the year on `jobs` threads.
+/// The threads take the next part to run from a shared counter, the records
+/// are returned in the same order as a sequential run.
+/// The default panic hook is silenced while running, the messages end up in the records
+pub fn run_all(
+    source: &InputSource,
+    answers: &[Entry],
+    year: u32,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Record> {
+    let tasks: Vec<(&'static dyn Challenge, u32)> = solution::solutions(year)
+        .iter()
+        .flat_map(|challenge| PARTS.map(|part| (*challenge, part)))
+        .collect();
+    let next = AtomicUsize::new(0);
+    let records: Mutex<Vec<Option<Record>>> = Mutex::new(vec![None; tasks.len()]);
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let (challenge, part) = match tasks.get(i) {
+                    Some(&task) => task,
+                    None => break,
+                };
+                // The record is timed inside `run`, waiting in the queue doesn't count
+                let record = run(challenge, part, source, answers, timeout);
+                records.lock().unwrap()[i] = Some(record);
+            });
+        }
+    });
+    panic::set_hook(hook);
+    records
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_all, Status};
+    use crate::solution::DEFAULT_YEAR;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+    #[test]
+    fn test_run_all_order() {
+        // Every input is missing so nothing is solved, only the order is checked
+        let source = InputSource::Resources {
+            dir: PathBuf::from("no_such_directory"),
+            example: true,
+        };
+        let sequential = run_all(&source, &[], DEFAULT_YEAR, 1, None);
+        let parallel = run_all(&source, &[], DEFAULT_YEAR, 4, None);
+        assert_eq!(sequential.len(), 50);
+        assert!(run_all(&source, &[], 2015, 1, None).is_empty());
+        for (i, (a, b)) in sequential.iter().zip(&parallel).enumerate() {
+            assert_eq!((a.day, a.part), (i as u32 / 2 + 1, i as u32 % 2 + 1));
+            assert_eq!((a.day, a.part), (b.day, b.part));
+            assert!(matches!(b.status, Status::Error(_)));
+        }
+    }
+}
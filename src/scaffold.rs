@@ -0,0 +1,379 @@
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use crate::answers::ANSWERS_FILE;
+use crate::error::Error;
+use crate::solution::DEFAULT_YEAR;
+use crate::utils::{challenge_data_path, write_file, RESOURCES};
+
+/// Starting point of every day, the same as an unsolved day.
+/// `{day}` and `{title}` are replaced, `{year}` becomes a `YEAR` line for other years than the default
+const DAY_TEMPLATE: &str = r#"use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u32 = {day};{year}
+    const TITLE: &'static str = "{title}";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::Int(0))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::Int(0))
+    }
+}
+"#;
+
+/// Module of a year without any day yet
+const YEAR_TEMPLATE: &str = r#"//! Solutions to [advent of code {year}](https://adventofcode.com/{year})
+
+use crate::solution::Challenge;
+
+/// The solutions of {year} ordered by day
+pub static SOLUTIONS: &[&dyn Challenge] = &[];
+"#;
+
+const SOLUTIONS_START: &str = "pub static SOLUTIONS: &[&dyn Challenge] = ";
+const YEARS_START: &str = "pub static YEARS: &[(u32, &[&dyn Challenge])] = ";
+
+/// Where `new_day` writes, relative to the root of the repository
+#[derive(Clone, Debug)]
+pub struct Layout {
+    pub src: PathBuf,
+    pub resources: PathBuf,
+    pub answers: PathBuf,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            src: PathBuf::from("src"),
+            resources: PathBuf::from(RESOURCES),
+            answers: PathBuf::from(ANSWERS_FILE),
+        }
+    }
+}
+
+impl Layout {
+    /// Generates `src/yearYYYY/dayN.rs` from the template, registers it in the `SOLUTIONS`
+    /// of its year (creating the year module and registering it in `YEARS` if needed),
+    /// creates empty inputs and adds commented answer entries to fill in.
+    /// An empty real input is downloaded when it is read, see `utils::read_or_fetch`.
+    /// Fails before writing anything if one of the new files already exists.
+    /// Returns the files written
+    pub fn new_day(&self, year: u32, day: u32, title: &str) -> Result<Vec<PathBuf>, Error> {
+        let year_dir = self.src.join(format!("year{}", year));
+        let module = year_dir.join(format!("day{}.rs", day));
+        let year_module = self.src.join(format!("year{}.rs", year));
+        let input = challenge_data_path(&self.resources, year, day, false);
+        let example = challenge_data_path(&self.resources, year, day, true);
+
+        if !(1..=25).contains(&day) {
+            return Err(scaffold_error(&module, "the days go from 1 to 25"));
+        }
+        for path in [&input, &example, &module] {
+            if path.exists() {
+                return Err(scaffold_error(
+                    path,
+                    "already exists, refusing to overwrite it",
+                ));
+            }
+        }
+        let year_source = if year_module.exists() {
+            read(&year_module)?
+        } else {
+            YEAR_TEMPLATE.replace("{year}", &year.to_string())
+        };
+        let year_source = register_day(&year_source, day)
+            .ok_or_else(|| scaffold_error(&year_module, "can't add the day to `SOLUTIONS`"))?;
+        // Only a new year needs to be registered, its files are checked before writing
+        let registry = if year_module.exists() {
+            None
+        } else {
+            let lib_path = self.src.join("lib.rs");
+            let solution_path = self.src.join("solution.rs");
+            let lib = add_module(&read(&lib_path)?, &format!("year{}", year));
+            let solution = register_year(&read(&solution_path)?, year)
+                .ok_or_else(|| scaffold_error(&solution_path, "can't find the `YEARS` array"))?;
+            Some([(lib_path, lib), (solution_path, solution)])
+        };
+
+        let year_line = if year == DEFAULT_YEAR {
+            String::new()
+        } else {
+            format!("\n    const YEAR: u32 = {};", year)
+        };
+        let source = DAY_TEMPLATE
+            .replace("{day}", &day.to_string())
+            .replace("{year}", &year_line)
+            .replace("{title}", &title.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut written = Vec::new();
+        write_file(&module, &source)?;
+        written.push(module);
+        write_file(&year_module, &year_source)?;
+        written.push(year_module);
+        for (path, content) in registry.into_iter().flatten() {
+            write_file(&path, &content)?;
+            written.push(path);
+        }
+        for path in [input, example] {
+            write_file(&path, "")?;
+            written.push(path);
+        }
+        // The answers are optional, the stubs are only added to an existing file
+        if self.answers.exists() {
+            if let Some(answers) = add_answer_stubs(&read(&self.answers)?, year, day) {
+                write_file(&self.answers, &answers)?;
+                written.push(self.answers.clone());
+            }
+        }
+        Ok(written)
+    }
+}
+
+fn scaffold_error(path: &Path, message: &str) -> Error {
+    Error::Scaffold {
+        path: path.to_path_buf(),
+        message: message.to_string(),
+    }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The numbers right after each `prefix` in `s`, like the days in `&day3::Day3`
+fn numbers_after(s: &str, prefix: &str) -> Vec<u32> {
+    s.match_indices(prefix)
+        .filter_map(|(i, _)| {
+            let digits: String = s[i + prefix.len()..]
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            digits.parse().ok()
+        })
+        .collect()
+}
+
+/// Where the array starting with `start` is, up to its `];`
+fn array_statement(source: &str, start: &str) -> Option<Range<usize>> {
+    let begin = source.find(start)?;
+    let end = begin + source[begin..].find("];")? + 2;
+    Some(begin..end)
+}
+
+/// Writes the array like rustfmt: on one line if it's short, one element per line otherwise
+fn render_array(start: &str, items: &[String]) -> String {
+    let one_line = items.join(", ");
+    if one_line.len() <= 60 && start.len() + one_line.len() + 3 <= 100 {
+        format!("{}&[{}];", start, one_line)
+    } else {
+        let lines: String = items
+            .iter()
+            .map(|item| format!("    {},\n", item))
+            .collect();
+        format!("{}&[\n{}];", start, lines)
+    }
+}
+
+/// Adds `pub mod dayN;` and `&dayN::DayN` to the module of a year.
+/// `None` if the day is already there or the `SOLUTIONS` array is missing
+fn register_day(source: &str, day: u32) -> Option<String> {
+    let range = array_statement(source, SOLUTIONS_START)?;
+    let mut days = numbers_after(&source[range.clone()], "&day");
+    if days.contains(&day) {
+        return None;
+    }
+    days.push(day);
+    days.sort_unstable();
+    let items: Vec<String> = days
+        .iter()
+        .map(|d| format!("&day{}::Day{}", d, d))
+        .collect();
+    let source = format!(
+        "{}{}{}",
+        &source[..range.start],
+        render_array(SOLUTIONS_START, &items),
+        &source[range.end..]
+    );
+    Some(add_module(&source, &format!("day{}", day)))
+}
+
+/// Adds the `SOLUTIONS` of the year to `YEARS`, `None` if the array is missing
+fn register_year(source: &str, year: u32) -> Option<String> {
+    let range = array_statement(source, YEARS_START)?;
+    let mut years = numbers_after(&source[range.clone()], "::year");
+    if !years.contains(&year) {
+        years.push(year);
+    }
+    years.sort_unstable();
+    let items: Vec<String> = years
+        .iter()
+        .map(|y| format!("({}, crate::year{}::SOLUTIONS)", y, y))
+        .collect();
+    Some(format!(
+        "{}{}{}",
+        &source[..range.start],
+        render_array(YEARS_START, &items),
+        &source[range.end..]
+    ))
+}
+
+/// Adds `pub mod name;` to the `pub mod` lines, which stay sorted like rustfmt does.
+/// Without `pub mod` lines it goes before the first item after the imports
+fn add_module(source: &str, name: &str) -> String {
+    let line = format!("pub mod {};", name);
+    let mut lines: Vec<&str> = source.lines().collect();
+    if lines.contains(&line.as_str()) {
+        return source.to_string();
+    }
+    let is_mod = |l: &&str| l.starts_with("pub mod ");
+    match lines.iter().position(is_mod) {
+        Some(first) => {
+            let count = lines[first..].iter().take_while(|l| is_mod(l)).count();
+            lines.insert(first, &line);
+            lines[first..=first + count].sort_unstable();
+        }
+        None => {
+            let first = lines
+                .iter()
+                .position(|l| l.starts_with("///") || l.starts_with("pub "))
+                .unwrap_or(lines.len());
+            lines.insert(first, "");
+            lines.insert(first, &line);
+        }
+    }
+    let mut s = lines.join("\n");
+    if source.ends_with('\n') {
+        s.push('\n');
+    }
+    s
+}
+
+/// Commented entries for the answers of the day, before the end of the `answer` array.
+/// `None` if the file doesn't end with the array
+fn add_answer_stubs(source: &str, year: u32, day: u32) -> Option<String> {
+    let end = source.trim_end().strip_suffix(']')?.len();
+    let year = if year == DEFAULT_YEAR {
+        String::new()
+    } else {
+        format!("year = {}, ", year)
+    };
+    let stubs: String = ["example", "real"]
+        .iter()
+        .flat_map(|input| [1, 2].map(|part| (input, part)))
+        .map(|(input, part)| {
+            format!(
+                "    # {{ {}day = {}, part = {}, input = \"{}\", expected = 0 }},\n",
+                year, day, part, input
+            )
+        })
+        .collect();
+    Some(format!("{}{}{}", &source[..end], stubs, &source[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_answer_stubs, register_day, register_year, Layout, YEAR_TEMPLATE};
+    use crate::inputs::{Fetcher, HttpClient};
+    use crate::utils::{challenge_data_path, read_or_fetch};
+    use std::fs;
+
+    /// Answers every request with the URL
+    struct Stub;
+
+    impl HttpClient for Stub {
+        fn get(&self, url: &str, _session: &str) -> Result<String, String> {
+            Ok(format!("{}\n", url))
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        let year = YEAR_TEMPLATE.replace("{year}", "2022");
+        let year = register_day(&year, 10).unwrap();
+        assert!(year.contains("pub mod day10;\n\n/// The solutions"));
+        assert!(year.contains("= &[&day10::Day10];"));
+        let year = register_day(&year, 2).unwrap();
+        assert!(year.contains("pub mod day10;\npub mod day2;\n"));
+        assert!(year.contains("= &[&day2::Day2, &day10::Day10];"));
+        assert_eq!(register_day(&year, 2), None);
+
+        let solution = "pub static YEARS: &[(u32, &[&dyn Challenge])] = &[(2021, crate::year2021::SOLUTIONS)];\n";
+        assert_eq!(
+            register_year(solution, 2022).unwrap(),
+            "pub static YEARS: &[(u32, &[&dyn Challenge])] = &[\n    (2021, crate::year2021::SOLUTIONS),\n    (2022, crate::year2022::SOLUTIONS),\n];\n"
+        );
+
+        let answers = add_answer_stubs("answer = [\n    { day = 1 },\n]\n", 2022, 3).unwrap();
+        assert!(answers.starts_with("answer = [\n    { day = 1 },\n    # { year = 2022, day = 3, part = 1, input = \"example\""));
+        assert!(answers.ends_with("expected = 0 },\n]\n"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let layout = Layout {
+            src: root.join("src"),
+            resources: root.join("resources"),
+            answers: root.join("answers.toml"),
+        };
+        fs::create_dir_all(&layout.src).unwrap();
+        fs::write(
+            layout.src.join("lib.rs"),
+            "pub mod utils;\npub mod year2021;\n",
+        )
+        .unwrap();
+        fs::write(
+            layout.src.join("solution.rs"),
+            "pub static YEARS: &[(u32, &[&dyn Challenge])] = &[(2021, crate::year2021::SOLUTIONS)];\n",
+        )
+        .unwrap();
+
+        let written = layout.new_day(2022, 1, "Calorie \"Counting\"").unwrap();
+        assert_eq!(written.len(), 6);
+        let module = fs::read_to_string(root.join("src/year2022/day1.rs")).unwrap();
+        assert!(module.contains("const YEAR: u32 = 2022;"));
+        assert!(module.contains(r#"const TITLE: &'static str = "Calorie \"Counting\"";"#));
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .ends_with("pub mod year2021;\npub mod year2022;\n"));
+        assert_eq!(
+            fs::read_to_string(root.join("resources/2022/day1example.txt")).unwrap(),
+            ""
+        );
+
+        // The empty real input gets downloaded
+        let input = challenge_data_path(&layout.resources, 2022, 1, false);
+        assert_eq!(fs::read_to_string(&input).unwrap(), "");
+        let fetcher = Fetcher::new(Stub, root.join("cache")).session("secret");
+        assert_eq!(
+            read_or_fetch(input, &fetcher, 2022, 1).unwrap(),
+            "https://adventofcode.com/2022/day/1/input\n"
+        );
+
+        // Nothing is overwritten
+        assert!(layout.new_day(2022, 1, "").is_err());
+        assert!(layout.new_day(2022, 26, "").is_err());
+        layout.new_day(2022, 2, "").unwrap();
+        assert!(fs::read_to_string(root.join("src/year2022.rs"))
+            .unwrap()
+            .contains("&[&day1::Day1, &day2::Day2]"));
+    }
+}
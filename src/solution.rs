@@ -0,0 +1,148 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::utils::read_challenge_data;
+
+/// The year used when none is given
+pub const DEFAULT_YEAR: u32 = 2021;
+
+/// A puzzle split into parsing and its two parts.
+/// Each `yearYYYY::dayN` module implements this on a unit struct `DayN`
+/// and registers it in the `SOLUTIONS` of its year.
+pub trait Solution {
+    /// Metadata about the puzzle
+    const DAY: u32;
+    const YEAR: u32 = DEFAULT_YEAR;
+    const TITLE: &'static str;
+    /// Version of the solver, bump it when the solution changes so cached answers are recomputed
+    const VERSION: u32 = 1;
+
+    /// What the raw input is parsed into. Both parts work on it.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Answer, Error>;
+    fn part2(input: &Self::Input) -> Result<Answer, Error>;
+
+    /// Reads the challenge data for the day, parses it and solves the given part
+    #[allow(unused)]
+    fn solve(run_example: bool, part1: bool) -> Result<Answer, Error> {
+        let input = Self::parse(&read_challenge_data(Self::YEAR, Self::DAY, run_example)?)?;
+        if part1 {
+            Self::part1(&input)
+        } else {
+            Self::part2(&input)
+        }
+    }
+}
+
+/// Object safe version of `Solution` so all the days can be kept in the same registry.
+/// Implemented for every `Solution`.
+pub trait Challenge: Sync {
+    fn day(&self) -> u32;
+    fn year(&self) -> u32;
+    fn title(&self) -> &'static str;
+    fn version(&self) -> u32;
+    /// Parses the raw input and solves part 1 or part 2 inside a `solve` span
+    fn run(&self, input: &str, part1: bool) -> Result<Answer, Error>;
+    /// Only parses the raw input, to check it without solving
+    fn check_input(&self, input: &str) -> Result<(), Error>;
+}
+
+impl<S> Challenge for S
+where
+    S: Solution + Sync,
+{
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+    fn version(&self) -> u32 {
+        S::VERSION
+    }
+    fn run(&self, input: &str, part1: bool) -> Result<Answer, Error> {
+        // The events of the solution are tagged with the day and the part
+        let part = if part1 { 1 } else { 2 };
+        let _span = tracing::info_span!("solve", day = S::DAY, part).entered();
+        let input = S::parse(input)?;
+        if part1 {
+            S::part1(&input)
+        } else {
+            S::part2(&input)
+        }
+    }
+    fn check_input(&self, input: &str) -> Result<(), Error> {
+        S::parse(input).map(|_| ())
+    }
+}
+
+/// The solutions of every year, oldest first.
+/// A new year gets its own `yearYYYY` module with a `SOLUTIONS` array registered here
+/// (`new <day> --year YYYY` does it)
+pub static YEARS: &[(u32, &[&dyn Challenge])] = &[(2021, crate::year2021::SOLUTIONS)];
+
+/// The solutions of the year ordered by day, empty if the year has none
+pub fn solutions(year: u32) -> &'static [&'static dyn Challenge] {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map_or(&[], |&(_, solutions)| solutions)
+}
+
+/// Every challenge has 2 parts
+pub const PARTS: [u32; 2] = [1, 2];
+
+/// Looks up the solution for a day of the year and checks that the part exists.
+/// The error lists the available solutions.
+pub fn find(year: u32, day: u32, part: u32) -> Result<&'static dyn Challenge, Error> {
+    solutions(year)
+        .iter()
+        .copied()
+        .find(|c| c.day() == day)
+        .filter(|_| PARTS.contains(&part))
+        .ok_or_else(|| Error::UnknownSolution {
+            year,
+            day,
+            part,
+            available: list(),
+        })
+}
+
+/// One line for each registered solution of every year: `year day part1,2 title`
+pub fn list() -> String {
+    YEARS
+        .iter()
+        .flat_map(|(_, solutions)| solutions.iter())
+        .map(|c| {
+            format!(
+                "{} day {:>2} (parts {}) {}\n",
+                c.year(),
+                c.day(),
+                PARTS.map(|p| p.to_string()).join(", "),
+                c.title()
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, DEFAULT_YEAR, YEARS};
+    #[test]
+    fn test_registry() {
+        for &(year, solutions) in YEARS {
+            assert!(solutions.iter().all(|c| c.year() == year));
+            // Ordered by day, days can be missing while a year is in progress
+            assert!(solutions.windows(2).all(|w| w[0].day() < w[1].day()));
+        }
+        assert_eq!(super::solutions(DEFAULT_YEAR).len(), 25);
+        assert!(find(DEFAULT_YEAR, 1, 2).is_ok());
+        assert!(find(DEFAULT_YEAR, 26, 1).is_err());
+        assert!(find(DEFAULT_YEAR, 1, 3).is_err());
+        assert!(find(2015, 1, 1).is_err());
+    }
+}
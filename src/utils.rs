@@ -1,28 +1,389 @@
+use crate::answers::InputKind;
+use crate::error::Error;
+use crate::inputs::{Fetcher, HttpClient};
+use crate::solution::DEFAULT_YEAR;
 use num::Integer;
+use sha2::{Digest, Sha256};
 use std::{
-    fs,
+    fmt, fs,
+    io::{self, Read},
+    ops::{Index, IndexMut},
     path::{Path, PathBuf},
 };
 
-pub fn read_challenge_data(challenge_number: u32, example: bool) -> String {
-    let path = if example {
-        Path::new("resources").join(format!("day{}example.txt", challenge_number))
+/// Default directory with the `dayN.txt` and `dayNexample.txt` files
+pub const RESOURCES: &str = "resources";
+
+/// Where the puzzle input is read from
+#[derive(Clone, Debug)]
+pub enum InputSource {
+    /// `dayN.txt` or `dayNexample.txt` from a directory, see `challenge_data_path`
+    Resources { dir: PathBuf, example: bool },
+    /// A given file, or stdin if the path is `-`
+    Path(PathBuf),
+}
+
+impl InputSource {
+    /// Reads the input for a day of the year.
+    /// The default `resources` are read with `read_challenge_data`, so a missing real input
+    /// is taken from the inputs cache or downloaded.
+    /// Other directories belong to other accounts, their inputs are never downloaded
+    pub fn read(&self, year: u32, challenge_number: u32) -> Result<String, Error> {
+        match self {
+            InputSource::Resources { dir, example } if dir == Path::new(RESOURCES) => {
+                read_challenge_data(year, challenge_number, *example)
+            }
+            InputSource::Resources { dir, example } => {
+                read_challenge_data_dir(dir, year, challenge_number, *example)
+            }
+            InputSource::Path(path) => read_challenge_data_path(path.clone()),
+        }
+    }
+
+    /// The file the input of the day is read from, `None` for stdin
+    pub fn path(&self, year: u32, challenge_number: u32) -> Option<PathBuf> {
+        match self {
+            InputSource::Resources { dir, example } => {
+                Some(challenge_data_path(dir, year, challenge_number, *example))
+            }
+            InputSource::Path(path) if path == Path::new("-") => None,
+            InputSource::Path(path) => Some(path.clone()),
+        }
+    }
+
+    /// If the input is from `resources` the known answers apply.
+    /// Returns the input of `answers.toml` they are given for
+    pub fn known_answers(&self) -> Option<InputKind> {
+        match self {
+            InputSource::Resources { dir, example } if dir == Path::new(RESOURCES) => {
+                Some(if *example {
+                    InputKind::Example
+                } else {
+                    InputKind::Real
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Reads `dayN.txt` or `dayNexample.txt` from `resources`.
+/// A missing real input is taken from the inputs cache, or downloaded if there is a
+/// session token (see `inputs::Fetcher::from_env`)
+pub fn read_challenge_data(
+    year: u32,
+    challenge_number: u32,
+    example: bool,
+) -> Result<String, Error> {
+    let path = challenge_data_path(Path::new(RESOURCES), year, challenge_number, example);
+    if example {
+        read_challenge_data_path(path)
+    } else {
+        read_or_fetch(path, &Fetcher::from_env(), year, challenge_number)
+    }
+}
+
+/// `dir/YEAR/dayN.txt` or `dir/YEAR/dayNexample.txt`.
+/// The files of the default year are directly in `dir`, like before there were other years
+pub fn challenge_data_path(dir: &Path, year: u32, challenge_number: u32, example: bool) -> PathBuf {
+    let dir = if year == DEFAULT_YEAR {
+        dir.to_path_buf()
     } else {
-        Path::new("resources").join(format!("day{}.txt", challenge_number))
+        dir.join(year.to_string())
     };
-    read_challenge_data_path(path)
+    if example {
+        dir.join(format!("day{}example.txt", challenge_number))
+    } else {
+        dir.join(format!("day{}.txt", challenge_number))
+    }
+}
+
+/// Same as `read_challenge_data` but the files are searched in `dir`
+pub fn read_challenge_data_dir(
+    dir: &Path,
+    year: u32,
+    challenge_number: u32,
+    example: bool,
+) -> Result<String, Error> {
+    read_challenge_data_path(challenge_data_path(dir, year, challenge_number, example))
+}
+
+/// Reads the real input of the day at `path`. If the file is missing the input is taken from the
+/// cache of `fetcher`, or downloaded if it has a session token.
+/// An empty file, like the one written by `new`, is missing too
+pub fn read_or_fetch<C: HttpClient>(
+    path: PathBuf,
+    fetcher: &Fetcher<C>,
+    year: u32,
+    challenge_number: u32,
+) -> Result<String, Error> {
+    let res = match read_challenge_data_path(path.clone()) {
+        Ok(input) if input.is_empty() => Err(Error::Io {
+            path,
+            source: io::Error::new(io::ErrorKind::NotFound, "the input is empty"),
+        }),
+        res => res,
+    };
+    match res {
+        Err(Error::Io { source, .. })
+            if source.kind() == io::ErrorKind::NotFound
+                && (fetcher.has_session() || fetcher.is_cached(year, challenge_number)) =>
+        {
+            fetcher.input(year, challenge_number)
+        }
+        res => res,
+    }
 }
 
-#[allow(unused)]
-pub fn read_challenge_data_path(path: PathBuf) -> String {
-    let s = match fs::read_to_string(&path) {
-        Err(e) => panic!("Couldn't open {}: {}", path.display(), e),
-        Ok(f) => f,
+/// Reads the whole file. If the path is `-` reads stdin
+pub fn read_challenge_data_path(path: PathBuf) -> Result<String, Error> {
+    let res = if path == Path::new("-") {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s).map(|_| s)
+    } else {
+        fs::read_to_string(&path)
     };
-    s
+    res.map_err(|source| Error::Io { path, source })
     //BufReader::new(file)
 }
 
+/// Writes the file, creating the directories on the way
+pub fn write_file(path: &Path, content: &str) -> Result<(), Error> {
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, content).map_err(io_error)
+}
+
+/// SHA-256 of the data as lowercase hex, used to tell inputs apart
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Formats the rows as CSV under the header
+pub fn csv_string<R>(header: &[&str], rows: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator,
+    R::Item: AsRef<[u8]>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    // Writing to a `Vec` can't fail
+    writer.write_record(header).unwrap();
+    for row in rows {
+        writer.write_record(row).unwrap();
+    }
+    String::from_utf8(writer.into_inner().unwrap_or_default()).unwrap_or_default()
+}
+
+/// A position in a `Grid`: (row, column)
+pub type Position = (usize, usize);
+
+/// Rectangular 2D map stored row by row
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a `rows` x `cols` grid with the value given by `f` for each position
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..rows)
+            .flat_map(|i| (0..cols).map(move |j| (i, j)))
+            .map(&mut f)
+            .collect();
+        Self { cells, rows, cols }
+    }
+
+    /// Parses a character map like `..#v>` with one row per line.
+    /// `f` converts a character to a cell, `None` makes it a parse error.
+    /// Checks that the map isn't empty and all the rows have the same length
+    pub fn parse(day: u32, input: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self, Error> {
+        Self::parse_lines(day, input.lines().enumerate().map(|(i, l)| (i + 1, l)), f)
+    }
+
+    /// Same as `parse` for maps that don't start on the first line.
+    /// The lines come with their line number, used for errors
+    pub fn parse_lines<'a>(
+        day: u32,
+        lines: impl Iterator<Item = (usize, &'a str)>,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, Error> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+        for (n, line) in lines {
+            let line = line.trim();
+            let mut len = 0;
+            for (j, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or_else(|| {
+                    Error::parse(day, n, j + 1, format!("unexpected character {:?}", c))
+                })?;
+                cells.push(cell);
+                len += 1;
+            }
+            if rows == 0 {
+                cols = len;
+            } else if len != cols {
+                return Err(Error::parse(
+                    day,
+                    n,
+                    1,
+                    format!("expected {} characters, found {}", cols, len),
+                ));
+            }
+            rows += 1;
+        }
+        if cells.is_empty() {
+            return Err(Error::parse(day, 1, 1, "the map is empty"));
+        }
+        Ok(Self { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (i, j): Position) -> Option<&T> {
+        if i < self.rows && j < self.cols {
+            self.cells.get(i * self.cols + j)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (i, j): Position) -> Option<&mut T> {
+        if i < self.rows && j < self.cols {
+            self.cells.get_mut(i * self.cols + j)
+        } else {
+            None
+        }
+    }
+
+    /// The position if signed coordinates fall inside the grid
+    pub fn checked(&self, (i, j): (isize, isize)) -> Option<Position> {
+        let (i, j) = (usize::try_from(i).ok()?, usize::try_from(j).ok()?);
+        (i < self.rows && j < self.cols).then_some((i, j))
+    }
+
+    /// Moves by `(di, dj)` from `pos`, wrapping around the edges like a torus
+    pub fn wrapping_step(&self, (i, j): Position, (di, dj): (isize, isize)) -> Position {
+        let i = (i as isize + di).rem_euclid(self.rows as isize);
+        let j = (j as isize + dj).rem_euclid(self.cols as isize);
+        (i as usize, j as usize)
+    }
+
+    /// Up, down, left and right neighbours inside the grid.
+    /// The iterator doesn't borrow the grid so cells can be changed while walking it
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.neighbours(pos, &[(-1, 0), (1, 0), (0, -1), (0, 1)])
+    }
+
+    /// Neighbours inside the grid, diagonals included
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.neighbours(
+            pos,
+            &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbours(
+        &self,
+        (i, j): Position,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> {
+        let (rows, cols) = (self.rows, self.cols);
+        deltas.iter().filter_map(move |&(di, dj)| {
+            let i = usize::try_from(i as isize + di).ok()?;
+            let j = usize::try_from(j as isize + dj).ok()?;
+            (i < rows && j < cols).then_some((i, j))
+        })
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter().skip(j).step_by(self.cols)
+    }
+
+    /// All the positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let (rows, cols) = (self.rows, self.cols);
+        (0..rows).flat_map(move |i| (0..cols).map(move |j| (i, j)))
+    }
+
+    /// The cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> + '_ {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", pos, rows, cols))
+    }
+}
+
+/// One line per row, the cells are written next to each other
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(i) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 pub fn wrap<T: Integer + Copy>(x: T, a: T, b: T) -> T {
     assert!(a < b);
     let range = b - a + num::one();
@@ -33,3 +394,51 @@ pub fn wrap<T: Integer + Copy>(x: T, a: T, b: T) -> T {
         a + res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{sha256_hex, Grid, InputSource};
+    use crate::error::Error;
+    use std::fs;
+    #[test]
+    fn test_other_resources_are_not_fetched() {
+        // Another directory is read like any file, a missing input stays missing
+        let other = tempfile::tempdir().unwrap();
+        let source = InputSource::Resources {
+            dir: other.path().to_path_buf(),
+            example: false,
+        };
+        assert!(matches!(source.read(2021, 1), Err(Error::Io { .. })));
+        fs::write(other.path().join("day1.txt"), "199\n").unwrap();
+        assert_eq!(source.read(2021, 1).unwrap(), "199\n");
+    }
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse(0, "123\n456\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.checked((-1, 0)), None);
+        assert_eq!(grid.checked((1, 1)), Some((1, 1)));
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(grid.wrapping_step((0, 2), (-1, 1)), (1, 0));
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert!(Grid::parse(0, "12\n3", |c| c.to_digit(10)).is_err());
+        assert!(Grid::parse(0, "1x", |c| c.to_digit(10)).is_err());
+        assert!(Grid::parse(0, "", |c| c.to_digit(10)).is_err());
+    }
+}
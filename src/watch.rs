@@ -0,0 +1,123 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::answer::Answer;
+
+/// How often the watched files are checked
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What is compared to notice a change: modification time and size
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Notices when files are written by checking their metadata.
+/// A file appearing or disappearing is a change too
+#[derive(Clone, Debug)]
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = stamp(&path);
+                    (path, stamp)
+                })
+                .collect(),
+        }
+    }
+
+    /// If a file changed since the last call, or since the watcher was made
+    pub fn changed(&mut self) -> bool {
+        let mut changed = false;
+        for (path, last) in &mut self.files {
+            let current = stamp(path);
+            if current != *last {
+                *last = current;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Blocks until a file changes
+    pub fn wait(&mut self, interval: Duration) {
+        while !self.changed() {
+            thread::sleep(interval);
+        }
+    }
+}
+
+/// Compares the answer to the one before the change.
+/// Changed lines are prefixed by `-` for the previous answer and `+` for the new one,
+/// so grids only show the rows that moved
+pub fn diff(previous: &Answer, current: &Answer) -> String {
+    if previous == current {
+        return format!("{} (unchanged)\n", current);
+    }
+    let previous = previous.to_string();
+    let current = current.to_string();
+    let (previous, current): (Vec<&str>, Vec<&str>) =
+        (previous.lines().collect(), current.lines().collect());
+    let mut s = String::new();
+    for i in 0..previous.len().max(current.len()) {
+        match (previous.get(i), current.get(i)) {
+            (Some(a), Some(b)) if a == b => s += &format!(" {}\n", a),
+            (a, b) => {
+                if let Some(a) = a {
+                    s += &format!("-{}\n", a);
+                }
+                if let Some(b) = b {
+                    s += &format!("+{}\n", b);
+                }
+            }
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Watcher};
+    use crate::answer::Answer;
+    use std::{
+        fs,
+        time::{Duration, SystemTime},
+    };
+    #[test]
+    fn test_watch() {
+        assert_eq!(diff(&Answer::Int(5), &Answer::Int(5)), "5 (unchanged)\n");
+        assert_eq!(diff(&Answer::Int(5), &Answer::Int(7)), "-5\n+7\n");
+        let grid = |rows: &[&str]| Answer::Grid(rows.iter().map(|r| r.to_string()).collect());
+        assert_eq!(
+            diff(&grid(&["#.", ".#"]), &grid(&["#.", "##", ".."])),
+            " #.\n-.#\n+##\n+..\n"
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        let mut watcher = Watcher::new([path.clone()]);
+        assert!(!watcher.changed());
+        fs::write(&path, "1\n").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+        // Same size, only the modification time tells
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        assert!(watcher.changed());
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.changed());
+    }
+}
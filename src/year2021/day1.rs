@@ -0,0 +1,236 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use tracing::debug;
+
+/// Parses the depth on line `line`
+fn parse_depth(line: usize, s: &str) -> Result<u32, Error> {
+    s.trim()
+        .parse::<u32>()
+        .map_err(|e| Error::parse(Day1::DAY, line, 1, format!("invalid depth {:?}: {}", s, e)))
+}
+
+/// Reads the depths of the file one line at a time, for logs too big to keep in memory.
+/// Use `itertools::process_results` to give them to `count_increases` and stop at the first error
+pub fn read_depths(path: &Path) -> Result<impl Iterator<Item = Result<u32, Error>>, Error> {
+    let path = path.to_path_buf();
+    let file = File::open(&path).map_err(|source| Error::Io {
+        path: path.clone(),
+        source,
+    })?;
+    Ok(BufReader::new(file)
+        .lines()
+        .enumerate()
+        .map(move |(i, line)| {
+            let line = line.map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+            parse_depth(i + 1, &line)
+        }))
+}
+
+/// The last `len` depths, oldest first
+struct Window {
+    depths: VecDeque<u32>,
+    len: usize,
+}
+
+impl Window {
+    /// Panics if `len` is 0, like `slice::windows`
+    fn new(len: usize) -> Self {
+        assert!(len > 0, "the window must hold at least 1 depth");
+        Self {
+            depths: VecDeque::with_capacity(len),
+            len,
+        }
+    }
+
+    /// Adds a depth. Returns the depth that leaves the window, the one `len` places before it
+    fn push(&mut self, depth: u32) -> Option<u32> {
+        let old = if self.is_full() {
+            self.depths.pop_front()
+        } else {
+            None
+        };
+        self.depths.push_back(depth);
+        old
+    }
+
+    fn is_full(&self) -> bool {
+        self.depths.len() == self.len
+    }
+
+    /// The depth added last
+    fn last(&self) -> Option<u32> {
+        self.depths.back().copied()
+    }
+}
+
+/// Number of times the sum of `window` consecutive depths is bigger than the sum before it.
+/// Two consecutive windows share all but their first and last depth, so comparing the sums
+/// is comparing each depth to the one `window` places before it: only `window` depths are kept.
+///
+/// Panics if `window` is 0, like `slice::windows`
+pub fn count_increases(depths: impl IntoIterator<Item = u32>, window: usize) -> usize {
+    let mut last = Window::new(window);
+    depths
+        .into_iter()
+        .filter(|&depth| last.push(depth).is_some_and(|old| depth > old))
+        .count()
+}
+
+/// The sums of every `window` consecutive depths, computed while reading them.
+/// Returned by `window_sums`
+pub struct WindowSums<I> {
+    depths: I,
+    last: Window,
+    sum: u64,
+}
+
+impl<I: Iterator<Item = u32>> Iterator for WindowSums<I> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let depth = self.depths.next()?;
+            self.sum += u64::from(depth);
+            self.sum -= self.last.push(depth).map_or(0, u64::from);
+            if self.last.is_full() {
+                return Some(self.sum);
+            }
+        }
+    }
+}
+
+/// Sums of the sliding windows of `window` depths, one per window.
+/// `window` can't be 0, like in `count_increases`
+pub fn window_sums<I: IntoIterator<Item = u32>>(
+    depths: I,
+    window: usize,
+) -> WindowSums<I::IntoIter> {
+    WindowSums {
+        depths: depths.into_iter(),
+        last: Window::new(window),
+        sum: 0,
+    }
+}
+
+/// Summary of a sonar log, see `analyse`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DepthStats {
+    /// Number of depths read
+    pub depths: usize,
+    /// Increases of the sums of `window` depths, the same as `count_increases`
+    pub increases: usize,
+    /// Most consecutive depths, each deeper than the one before
+    pub longest_increasing_run: usize,
+    /// Biggest decrease from a depth to the next one, `None` if it never goes down
+    pub largest_drop: Option<u32>,
+}
+
+/// Reads the whole log once, keeping only `window` depths.
+/// `window` can't be 0, like in `count_increases`
+pub fn analyse(depths: impl IntoIterator<Item = u32>, window: usize) -> DepthStats {
+    let mut stats = DepthStats::default();
+    let mut previous = Window::new(window);
+    let mut run = 0;
+    for depth in depths {
+        stats.depths += 1;
+        match previous.last() {
+            Some(last) if depth > last => run += 1,
+            Some(last) => {
+                if depth < last {
+                    stats.largest_drop = stats.largest_drop.max(Some(last - depth));
+                }
+                run = 1;
+            }
+            None => run = 1,
+        }
+        stats.longest_increasing_run = stats.longest_increasing_run.max(run);
+        if previous.push(depth).is_some_and(|old| depth > old) {
+            stats.increases += 1;
+        }
+    }
+    stats
+}
+
+/// Pretty straightforward
+/// Part 1 compares each depth to the previous one, part 2 each sum of 3 depths to the previous sum.
+/// Two sums of 3 only differ by the depth that enters and the one that leaves the `Window`,
+/// so both parts are `count_increases`, with a window of 1 and of 3 depths.
+/// `window_sums` gives the sums themselves when they are needed
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_depth(i + 1, line))
+            .collect()
+    }
+
+    fn part1(depths: &Self::Input) -> Result<Answer, Error> {
+        let count = count_increases(depths.iter().copied(), 1);
+        debug!("count: {}", count); // 1759
+        Ok(Answer::from(count))
+    }
+
+    fn part2(depths: &Self::Input) -> Result<Answer, Error> {
+        let count = count_increases(depths.iter().copied(), 3);
+        debug!("count: {}", count); // 1805
+        Ok(Answer::from(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{analyse, count_increases, read_depths, window_sums, DepthStats};
+    #[test]
+    fn test_depth_analysis() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(count_increases(depths, 1), 7);
+        assert_eq!(count_increases(depths, 3), 5);
+        assert_eq!(
+            window_sums(depths, 3).collect::<Vec<_>>(),
+            [607, 618, 618, 617, 647, 716, 769, 792]
+        );
+        assert_eq!(
+            analyse(depths, 3),
+            DepthStats {
+                depths: 10,
+                increases: 5,
+                longest_increasing_run: 4,
+                largest_drop: Some(10),
+            }
+        );
+
+        // Streamed, never collected
+        let sawtooth = (0..1_000_000u32).map(|i| i % 10);
+        assert_eq!(count_increases(sawtooth.clone(), 1), 900_000);
+        assert_eq!(analyse(sawtooth, 10).largest_drop, Some(9));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("depths.txt");
+        std::fs::write(&path, "199\n200\n208\n210\n").unwrap();
+        let count = itertools::process_results(read_depths(&path).unwrap(), |depths| {
+            count_increases(depths, 1)
+        });
+        assert_eq!(count.unwrap(), 3);
+        std::fs::write(&path, "199\ndeep\n").unwrap();
+        let count = itertools::process_results(read_depths(&path).unwrap(), |depths| {
+            count_increases(depths, 1)
+        });
+        assert!(count.is_err());
+    }
+}
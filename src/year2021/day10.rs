@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use tracing::debug;
+
+fn score_illegal(c: char) -> Option<isize> {
+    match c {
+        ')' => Some(3),
+        ']' => Some(57),
+        '}' => Some(1197),
+        '>' => Some(25137),
+        _ => None,
+    }
+}
+fn match_illegal(open: char, close: char) -> Option<isize> {
+    match (open, close) {
+        (_, ')') if open != '(' => Some(3),
+        (_, ']') if open != '[' => Some(57),
+        (_, '}') if open != '{' => Some(1197),
+        (_, '>') if open != '<' => Some(25137),
+        _ => None,
+    }
+}
+
+fn score_autocomplete(c: char) -> Option<isize> {
+    match c {
+        '(' => Some(1),
+        '[' => Some(2),
+        '{' => Some(3),
+        '<' => Some(4),
+        _ => None,
+    }
+}
+/// Goes through the line with a stack of opening chars.
+/// Returns the score of the first illegal char if the line is corrupted,
+/// otherwise the stack with the unclosed chars.
+fn check_line(line: &str) -> Result<Vec<char>, isize> {
+    let openings: HashSet<char> = ['(', '[', '{', '<'].into();
+    let mut stack: Vec<char> = Vec::new();
+    for c in line.chars() {
+        // If it's an opening char push it
+        if openings.contains(&c) {
+            stack.push(c);
+        } else {
+            // If the stack is empty return the illegal score
+            if stack.is_empty() {
+                return Err(score_illegal(c).unwrap());
+            } else {
+                // Else pop the last char (it's an opening char) and check it against the closing char
+                // If they don't match we return the score
+                if let Some(score) = match_illegal(stack.pop().unwrap(), c) {
+                    return Err(score);
+                }
+                // If everything is fine we continue
+            }
+        }
+    }
+    Ok(stack)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        for (i, line) in input.lines().enumerate() {
+            // Only brackets so the scores can be unwrapped
+            if let Some((j, c)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !"()[]{}<>".contains(*c))
+            {
+                return Err(Error::parse(
+                    Self::DAY,
+                    i + 1,
+                    j + 1,
+                    format!("{:?} is not a bracket", c),
+                ));
+            }
+        }
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, Error> {
+        // score for part 1
+        let s1: isize = lines.iter().filter_map(|line| check_line(line).err()).sum();
+        debug!("{}", s1); // 39467
+        Ok(Answer::from(s1))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, Error> {
+        // The stack will retain all the unclosed characters
+        // We just add up the points according to the formula
+        let mut s2 = Vec::new(); // score for part 2
+        for stack in lines.iter().filter_map(|line| check_line(line).ok()) {
+            let mut t = 0;
+            for &c in stack.iter().rev() {
+                //print!("{}")
+                t = 5 * t + score_autocomplete(c).unwrap() as u64;
+            }
+            //println!("{}", t);
+            s2.push(t);
+        }
+        if s2.is_empty() {
+            return Err(Error::invalid(Self::DAY, "all the lines are corrupted"));
+        }
+        s2.sort_unstable();
+        debug!("{}", s2[s2.len() / 2]); // 2380061249
+        Ok(Answer::from(s2[s2.len() / 2]))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use std::{collections::HashSet, fmt};
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::utils::{Grid, Position};
+use tracing::debug;
+
+type Point = Position;
+
+/// Simple struct that keeps the energy level and a flag to mark if the octopus flashed this round
+#[derive(Clone, Debug)]
+pub struct DumboOctopus {
+    energy: isize,
+    has_flashed: bool,
+}
+
+/// Shows the energy level, so the cavern prints like the input
+impl fmt::Display for DumboOctopus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.energy)
+    }
+}
+
+impl DumboOctopus {
+    fn flash(&mut self) {
+        self.energy = 0;
+        self.has_flashed = true;
+    }
+}
+
+/// Runs the steps and returns the number of flashes and the number of steps.
+/// For part 1 stops after 100 steps, for part 2 when all the octopuses flash
+fn simulate(cavern: &Grid<DumboOctopus>, part1: bool) -> (usize, usize) {
+    let mut cavern = cavern.clone();
+
+    // For part 1 we will break the step at 100 and for part 2 when all flash
+    // To solve this we will use a queue to keep the positions of the octopuses
+    // that flashed and finish the step only when the queue is empty.
+    // If the queue is not empty we go through the points and update the neighbours
+    // and if we find another octopus that flashed we put it in the queue
+    let mut c = 0; // for part 1
+    let mut step = 0; // for part 2
+    loop {
+        step += 1;
+        let mut queue: HashSet<Point> = HashSet::new(); // Empty queue for each step
+
+        // + 1 all
+        for (pos, octopus) in cavern.iter_mut() {
+            octopus.energy += 1;
+            if octopus.energy > 9 {
+                octopus.flash();
+                c += 1;
+                queue.insert(pos); // Add position of the octopuss that flashed to the queue
+            }
+        }
+        // While the queue is not empty we update the octopus' neighbours
+        // If we find another one that flashed we put it in the queue
+        while !queue.is_empty() {
+            //println!("{:?}", queue);
+            let mut to_add = HashSet::new(); // for updating the HashSet later
+            for point in queue.drain() {
+                // The grid checks the boundary and we only update if the neighbour hasn't flashed this round
+                for neighbour in cavern.neighbours8(point) {
+                    let octopus = &mut cavern[neighbour];
+                    if octopus.has_flashed {
+                        continue;
+                    }
+                    octopus.energy += 1;
+                    // If we get to the energy threshhold we flash the octopus and we put it in the queue
+                    if octopus.energy > 9 {
+                        octopus.flash();
+                        c += 1;
+                        to_add.insert(neighbour);
+                    }
+                }
+            }
+            queue.extend(to_add);
+        }
+        if part1 && step == 100 || cavern.iter().all(|(_, octo)| octo.has_flashed) {
+            break;
+        }
+        // Reset Dumbos has_flashed flag
+        for (_, oct) in cavern.iter_mut() {
+            oct.has_flashed = false;
+        }
+        //println!("{}", cavern);
+    }
+    (c, step)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+    type Input = Grid<DumboOctopus>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        // Our matrix will be a
+        let cavern = Grid::parse(Self::DAY, input, |c| {
+            c.to_digit(10).map(|energy| DumboOctopus {
+                energy: energy as isize,
+                has_flashed: false,
+            })
+        })?;
+        //println!("{:?}", cavern);
+        Ok(cavern)
+    }
+
+    fn part1(cavern: &Self::Input) -> Result<Answer, Error> {
+        let (c, _) = simulate(cavern, true);
+        debug!("{}", c); // 1608
+        Ok(Answer::from(c))
+    }
+
+    fn part2(cavern: &Self::Input) -> Result<Answer, Error> {
+        let (_, step) = simulate(cavern, false);
+        debug!("{}", step); // 214
+        Ok(Answer::from(step))
+    }
+}
\ No newline at end of file
@@ -1,13 +1,16 @@
 use itertools::Itertools;
+use tracing::debug;
 
-use crate::utils::read_challenge_data;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
 use std::{
     collections::{HashMap, HashSet},
     fmt,
 };
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-enum CaveType {
+pub enum CaveType {
     Small,
     Big,
 }
@@ -22,7 +25,7 @@ fn get_cave_type(s: &str) -> CaveType {
 }
 
 /// Undirected graph based on adjacency list
-struct UndirectedGraph<N> {
+pub struct UndirectedGraph<N> {
     node_list: HashMap<String, N>,
     adjacency_list: HashMap<String, HashSet<String>>,
 }
@@ -37,11 +40,20 @@ where
             .finish()
     }
 }
+impl<N> Default for UndirectedGraph<N>
+where
+    N: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<N> UndirectedGraph<N>
 where
     N: Clone,
 {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             node_list: HashMap::new(),
             adjacency_list: HashMap::new(),
@@ -50,7 +62,7 @@ where
 
     /// Adds node to graph.
     ///  Returns true if node was inserted or false if the node already exists
-    fn add_node(&mut self, u: &str, data: N) -> bool {
+    pub fn add_node(&mut self, u: &str, data: N) -> bool {
         if self.node_list.contains_key(u) {
             return false;
         }
@@ -60,7 +72,7 @@ where
     }
 
     /// Adds edge between node u and v. Returns an error if any of the nodes isn't found in the graph;
-    fn add_edge(&mut self, u: &str, v: &str) -> Result<(), String> {
+    pub fn add_edge(&mut self, u: &str, v: &str) -> Result<(), String> {
         self.node_exists(u)?;
         self.node_exists(v)?;
         let u_adj_list = self.adjacency_list.get_mut(u).unwrap();
@@ -70,7 +82,7 @@ where
         Ok(())
     }
     /// Gets the nodes' adjacency list. Returns error if the node is not found
-    fn neighbours(&self, u: &str) -> Result<HashSet<String>, String> {
+    pub fn neighbours(&self, u: &str) -> Result<HashSet<String>, String> {
         self.node_exists(u)?;
         // Checked for unwrap above
         let neighbours = self.adjacency_list.get(u).unwrap().clone();
@@ -78,29 +90,27 @@ where
     }
 
     /// gets the nodes' data
-    fn node_data(&self, u: &str) -> Result<N, String> {
+    pub fn node_data(&self, u: &str) -> Result<N, String> {
         self.node_exists(u)?;
         let data = self.node_list.get(u).unwrap().clone();
         Ok(data)
     }
 
     /// gets a reference to  nodes' data
-    #[allow(unused)]
-    fn node_data_ref(&self, u: &str) -> Result<&N, String> {
+    pub fn node_data_ref(&self, u: &str) -> Result<&N, String> {
         self.node_exists(u)?;
         let data = self.node_list.get(u).unwrap();
         Ok(data)
     }
 
     /// gets a mutable reference to  nodes' data
-    #[allow(unused)]
-    fn node_data_ref_mut(&mut self, u: &str) -> Result<&mut N, String> {
+    pub fn node_data_ref_mut(&mut self, u: &str) -> Result<&mut N, String> {
         self.node_exists(u)?;
         let data = self.node_list.get_mut(u).unwrap();
         Ok(data)
     }
 
-    fn node_exists(&self, u: &str) -> Result<(), String> {
+    pub fn node_exists(&self, u: &str) -> Result<(), String> {
         if !self.node_list.contains_key(u) {
             let msg = format!("Node {} is not found in the graph.", u);
             return Err(msg);
@@ -145,46 +155,64 @@ fn backtrack(
     c
 }
 
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(12, run_example);
-    let mut g = UndirectedGraph::new();
-    for line in input.lines() {
-        let (from, to): (&str, &str) = line.split('-').tuples().next().unwrap();
-        let from_type = get_cave_type(from);
-        let to_type = get_cave_type(to);
-        g.add_node(from, from_type);
-        g.add_node(to, to_type);
-        g.add_edge(from, to).unwrap(); // should panic if node is not in the list
-    }
-    // println!("{:?}", g);
-    // println!("{:?}", g.adjacency_list);
-
+/// Counts the paths from `start` to `end`.
+/// `twice` tells if a small cave was already visited twice
+fn count_paths(g: &UndirectedGraph<CaveType>, twice: bool) -> u64 {
     let start = "start";
     let end = "end";
     let mut visited: HashSet<String> = HashSet::new();
     visited.insert(start.to_string());
 
-    let c = if part1 {
-        backtrack(start, start, end, &mut visited, &g, true) // 4167
-    } else {
-        backtrack(start, start, end, &mut visited, &g, false) // 98441
-    };
-    println!("{:?}", c);
-    c as usize
+    backtrack(start, start, end, &mut visited, g, twice)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    #[test]
-    fn test_example() {
-        assert_eq!(solve(true, true), 10);
-        assert_eq!(solve(true, false), 36);
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+    type Input = UndirectedGraph<CaveType>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut g = UndirectedGraph::new();
+        for (i, line) in input.lines().enumerate() {
+            let (from, to): (&str, &str) =
+                line.trim().split('-').collect_tuple().ok_or_else(|| {
+                    Error::parse(
+                        Self::DAY,
+                        i + 1,
+                        1,
+                        format!("expected `<cave>-<cave>`, got {:?}", line),
+                    )
+                })?;
+            if from.is_empty() || to.is_empty() {
+                return Err(Error::parse(Self::DAY, i + 1, 1, "empty cave name"));
+            }
+            let from_type = get_cave_type(from);
+            let to_type = get_cave_type(to);
+            g.add_node(from, from_type);
+            g.add_node(to, to_type);
+            g.add_edge(from, to)
+                .map_err(|msg| Error::parse(Self::DAY, i + 1, 1, msg))?;
+        }
+        // println!("{:?}", g);
+        // println!("{:?}", g.adjacency_list);
+        for cave in ["start", "end"] {
+            g.node_exists(cave)
+                .map_err(|_| Error::invalid(Self::DAY, format!("there is no `{}` cave", cave)))?;
+        }
+        Ok(g)
+    }
+
+    fn part1(g: &Self::Input) -> Result<Answer, Error> {
+        let c = count_paths(g, true); // 4167
+        debug!("{:?}", c);
+        Ok(Answer::from(c))
     }
-    #[test]
 
-    fn test_problem() {
-        assert_eq!(solve(false, true), 4167);
-        assert_eq!(solve(false, false), 98441);
+    fn part2(g: &Self::Input) -> Result<Answer, Error> {
+        let c = count_paths(g, false); // 98441
+        debug!("{:?}", c);
+        Ok(Answer::from(c))
     }
 }
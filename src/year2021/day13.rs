@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::{column, Error};
+use crate::ocr;
+use crate::solution::Solution;
+use tracing::{debug, warn};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Horizontal,
+    Vertical,
+}
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+impl Point {
+    /// Flips along the given axis identified by coordinate and direction
+    /// If the point is on the good side doesn't flip.
+    /// Returns false if the point would end up on a negative coordinate
+    fn flip(&mut self, v: usize, dir: Direction) -> bool {
+        let c = match dir {
+            Direction::Horizontal => &mut self.x,
+            Direction::Vertical => &mut self.y,
+        };
+        if *c > v {
+            match v.checked_sub(*c - v) {
+                Some(flipped) => *c = flipped,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Decode the command into direction and line
+/// `line` is the line number of the command, used for errors
+fn decode_command(line: usize, s: &str) -> Result<(usize, Direction), Error> {
+    //let t: Vec<&str> = s[11..].split('=').collect();
+    let (dir, v) = s
+        .trim()
+        .strip_prefix("fold along ")
+        .and_then(|s| s.split('=').collect_tuple())
+        .ok_or_else(|| {
+            Error::parse(
+                Day13::DAY,
+                line,
+                1,
+                format!("expected `fold along <x|y>=<n>`, got {:?}", s),
+            )
+        })?;
+    let v: usize = v.parse().map_err(|e| {
+        Error::parse(
+            Day13::DAY,
+            line,
+            column(s, v),
+            format!("invalid fold line {:?}: {}", v, e),
+        )
+    })?;
+    let dir = match dir {
+        "x" => Direction::Horizontal,
+        "y" => Direction::Vertical,
+        _ => {
+            return Err(Error::parse(
+                Day13::DAY,
+                line,
+                column(s, dir),
+                format!("invalid fold axis {:?}", dir),
+            ))
+        }
+    };
+    Ok((v, dir))
+}
+
+/// The dots on the paper and the fold instructions
+pub struct Paper {
+    points: HashSet<Point>,
+    folds: Vec<(usize, Direction)>,
+}
+
+/// Folds the paper along the first `n` instructions
+fn fold(paper: &Paper, n: usize) -> Result<HashSet<Point>, Error> {
+    let mut points = paper.points.clone();
+    for &(v, dir) in paper.folds.iter().take(n) {
+        let mut to_add = HashSet::new();
+        for mut p in points.drain() {
+            if !p.flip(v, dir) {
+                return Err(Error::invalid(
+                    Day13::DAY,
+                    format!("folding along {:?} {} moves {:?} off the paper", dir, v, p),
+                ));
+            }
+            to_add.insert(p);
+        }
+        points.extend(to_add);
+    }
+    Ok(points)
+}
+
+/// Lit pixels where the points are, cropped to the bottom right point
+fn draw(points: &HashSet<Point>) -> Vec<Vec<bool>> {
+    let mut cols = 0;
+    let mut rows = 0;
+    for p in points {
+        cols = cols.max(p.x + 1);
+        rows = rows.max(p.y + 1);
+    }
+    //println!("{}, {}", rows, cols);
+    let mut t = vec![vec![false; cols]; rows];
+    for p in points {
+        t[p.y][p.x] = true;
+    }
+    t
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+    /// The example draws a square instead of letters
+    type Input = Paper;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut points: HashSet<Point> = HashSet::new();
+        let mut lines = input.lines();
+        // Read until the newline
+        let mut n = 0;
+        for line in &mut lines {
+            n += 1;
+            if line.trim().is_empty() {
+                break;
+            }
+            let (x, y) = line
+                .trim()
+                .splitn(2, ',')
+                .map(|s| {
+                    s.parse().map_err(|e| {
+                        Error::parse(
+                            Self::DAY,
+                            n,
+                            column(line, s),
+                            format!("invalid coordinate {:?}: {}", s, e),
+                        )
+                    })
+                })
+                .collect_tuple()
+                .ok_or_else(|| {
+                    Error::parse(
+                        Self::DAY,
+                        n,
+                        1,
+                        format!("expected `<x>,<y>`, got {:?}", line),
+                    )
+                })?;
+            let p = Point { x: x?, y: y? };
+            points.insert(p);
+        }
+        let folds = lines
+            .enumerate()
+            .map(|(i, line)| decode_command(n + i + 1, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Paper { points, folds })
+    }
+
+    /// Only the first fold for part 1
+    fn part1(paper: &Self::Input) -> Result<Answer, Error> {
+        let points = fold(paper, 1)?;
+        debug!("{:?}", points.len()); // 678
+        Ok(Answer::from(points.len()))
+    }
+
+    /// The folded paper shows letters.
+    /// If they can't be read the dots are returned instead
+    fn part2(paper: &Self::Input) -> Result<Answer, Error> {
+        let points = fold(paper, paper.folds.len())?;
+        let pixels = draw(&points);
+        match ocr::read(&pixels) {
+            Ok(letters) => Ok(Answer::from(letters)), // ECFHLHZE
+            Err(e) => {
+                warn!("{}", e);
+                Ok(Answer::grid(&pixels))
+            }
+        }
+    }
+}
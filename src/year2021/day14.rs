@@ -2,31 +2,41 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-use crate::utils::read_challenge_data;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use tracing::debug;
 
-fn decode_line(s: &str) -> ((char, char), char) {
-    let t: Vec<&str> = s.split(" -> ").collect();
-    let (c1, c2) = t[0].chars().tuples().next().unwrap();
-    let c3 = t[1].chars().next().unwrap();
+/// Decodes a `XY -> Z` rule. `line` is the line number of the rule, used for errors
+fn decode_line(line: usize, s: &str) -> Result<((char, char), char), Error> {
+    let err = || {
+        Error::parse(
+            Day14::DAY,
+            line,
+            1,
+            format!("expected `XY -> Z`, got {:?}", s),
+        )
+    };
+    let (pair, element) = s.trim().split(" -> ").collect_tuple().ok_or_else(err)?;
+    let (c1, c2) = pair.chars().collect_tuple().ok_or_else(err)?;
+    let (c3,) = element.chars().collect_tuple().ok_or_else(err)?;
 
-    ((c1, c2), c3)
+    Ok(((c1, c2), c3))
 }
 
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(14, run_example);
-    let mut lines = input.lines();
-    let template = lines.next().unwrap().to_string();
-    let mut rules = HashMap::new();
-    lines.next();
-    for line in lines {
-        let ((c1, c2), c3) = decode_line(line);
-        rules.insert((c1, c2), c3);
-    }
+/// The polymer template and the pair insertion rules
+pub struct Manual {
+    template: String,
+    rules: HashMap<(char, char), char>,
+}
+
+/// Difference between the most and least common element after `steps` steps.
+/// The template is never empty, it's checked when parsing
+fn polymerize(manual: &Manual, steps: usize) -> usize {
+    let Manual { template, rules } = manual;
     let mut pair_freq: HashMap<(char, char), u64> = HashMap::new();
     let mut char_freq = HashMap::new();
 
-    let steps = if part1 { 10 } else { 40 };
-
     // Naive solution -- works for part 1
     // let mut old_polymer = template.clone();
     // for _ in 0..steps {
@@ -80,26 +90,47 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
     let f_ = char_freq.entry(&last_char).or_insert(0);
     *f_ += 1;
 
-    println!("{}", template);
-    println!("{:?}", char_freq);
+    debug!("{}", template);
+    debug!("{:?}", char_freq);
     let c_max = char_freq.values().max().unwrap();
     let c_min = char_freq.values().min().unwrap();
-    println!("{}", c_max - c_min);
+    debug!("{}", c_max - c_min);
     (c_max - c_min) as usize
 }
 
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    #[test]
-    fn test_example() {
-        assert_eq!(solve(true, true), 1588);
-        assert_eq!(solve(true, false), 2188189693529);
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut lines = input.lines();
+        let template = lines.next().unwrap_or_default().trim().to_string();
+        if template.is_empty() {
+            return Err(Error::parse(
+                Self::DAY,
+                1,
+                1,
+                "the polymer template is empty",
+            ));
+        }
+        let mut rules = HashMap::new();
+        lines.next();
+        for (i, line) in lines.enumerate() {
+            // The rules start on the third line
+            let ((c1, c2), c3) = decode_line(i + 3, line)?;
+            rules.insert((c1, c2), c3);
+        }
+        Ok(Manual { template, rules })
+    }
+
+    fn part1(manual: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(polymerize(manual, 10)))
     }
-    #[test]
 
-    fn test_problem() {
-        assert_eq!(solve(false, true), 3284);
-        assert_eq!(solve(false, false), 4302675529689);
+    fn part2(manual: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(polymerize(manual, 40)))
     }
 }
@@ -1,26 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::utils::read_challenge_data;
-
-type Position = (usize, usize);
-fn neighbours(pos: &Position, size: &Position) -> Vec<Position> {
-    let (i, j) = *pos;
-    let (rows, cols) = *size;
-    let mut res = Vec::new();
-    if i >= 1 {
-        res.push((i - 1, j));
-    }
-    if j >= 1 {
-        res.push((i, j - 1));
-    }
-    if i < rows - 1 {
-        res.push((i + 1, j));
-    }
-    if j < cols - 1 {
-        res.push((i, j + 1));
-    }
-    res
-}
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::utils::{Grid, Position};
+use tracing::debug;
 
 fn manhattan_distance(pos1: &Position, pos2: &Position) -> u64 {
     ((pos1.0 as isize - pos2.0 as isize).abs() + (pos1.1 as isize - pos2.1 as isize).abs()) as u64
@@ -28,8 +13,8 @@ fn manhattan_distance(pos1: &Position, pos2: &Position) -> u64 {
 
 #[allow(unused)]
 fn euclidean_distance(pos1: &Position, pos2: &Position) -> u64 {
-    ((((pos1.0 as f64 - pos2.0 as f64).powi(2) + (pos1.1 as f64 - pos2.1 as f64).powi(2)) as f64)
-        .sqrt()) as u64
+    (((pos1.0 as f64 - pos2.0 as f64).powi(2) + (pos1.1 as f64 - pos2.1 as f64).powi(2)).sqrt())
+        as u64
 }
 fn reconstruct_path(
     parents: &HashMap<Position, Position>,
@@ -46,8 +31,7 @@ fn reconstruct_path(
     v
 }
 /// https://en.wikipedia.org/wiki/A*_search_algorithm
-fn astar(start: Position, end: Position, map: &[Vec<u64>]) -> Option<Vec<Position>> {
-    let map_size = (map.len(), map[0].len());
+fn astar(start: Position, end: Position, map: &Grid<u64>) -> Result<Option<Vec<Position>>, Error> {
     let mut open = HashSet::new();
     open.insert(start);
     let mut parents: HashMap<Position, Position> = HashMap::new();
@@ -60,6 +44,8 @@ fn astar(start: Position, end: Position, map: &[Vec<u64>]) -> Option<Vec<Positio
     f_scores.insert(start, manhattan_distance(&start, &end));
 
     loop {
+        // Each step looks through all of `open`, the extended cavern takes a while
+        cancel::checkpoint(Day15::DAY)?;
         // println!("Open: {:?}", open);
         // println!("g_scores: {:?}", g_scores);
         // println!("f_scores: {:?}", f_scores);
@@ -77,12 +63,12 @@ fn astar(start: Position, end: Position, map: &[Vec<u64>]) -> Option<Vec<Positio
         // If we reached the end break
         //println!("{:?}", current);
         if current == end {
-            return Some(reconstruct_path(&parents, &current, &start));
+            return Ok(Some(reconstruct_path(&parents, &current, &start)));
         }
         open.remove(&current);
-        for neighbour in neighbours(&current, &map_size) {
+        for neighbour in map.neighbours4(current) {
             // Distance from start to neighbour through current
-            let t_score = *g_scores.get(&current).unwrap_or(&0) + map[neighbour.0][neighbour.1];
+            let t_score = *g_scores.get(&current).unwrap_or(&0) + map[neighbour];
             // Path is better, record it.
             if t_score < *g_scores.get(&neighbour).unwrap_or(&u64::MAX) {
                 parents.insert(neighbour, current); // set parent to current
@@ -97,67 +83,52 @@ fn astar(start: Position, end: Position, map: &[Vec<u64>]) -> Option<Vec<Positio
             break;
         }
     }
-    None
+    Ok(None)
 }
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(15, run_example);
-    let mut cavern: Vec<Vec<u64>> = Vec::new();
-    for line in input.lines() {
-        let t: Vec<u64> = line
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as u64)
-            .collect();
-
-        cavern.push(t);
-    }
-
-    // Make the cavern 5 times bigger according to the rules
-    if !part1 {
-        let mut new_cavern = Vec::with_capacity(5 * cavern.len());
-        for i in 0..5 {
-            for row in &cavern {
-                let mut t_extended = Vec::new();
-                for j in 0..5 {
-                    t_extended.extend(row.iter().map(|e| {
-                        let e = *e + i + j;
-                        if e <= 9 {
-                            e
-                        } else {
-                            e % 10 + 1
-                        }
-                    }))
-                }
-                new_cavern.push(t_extended);
-            }
+/// Make the cavern 5 times bigger according to the rules
+fn extend(cavern: &Grid<u64>) -> Grid<u64> {
+    let (rows, cols) = (cavern.rows(), cavern.cols());
+    Grid::from_fn(5 * rows, 5 * cols, |(i, j)| {
+        // Each tile adds 1 to the risk
+        let e = cavern[(i % rows, j % cols)] + (i / rows + j / cols) as u64;
+        if e <= 9 {
+            e
+        } else {
+            e % 10 + 1
         }
-        cavern = new_cavern;
-    }
-    let end = (cavern.len() - 1, cavern[0].len() - 1);
+    })
+}
+
+/// Total risk of the lowest risk path from the top left to the bottom right
+fn lowest_risk(cavern: &Grid<u64>) -> Result<usize, Error> {
+    let end = (cavern.rows() - 1, cavern.cols() - 1);
     //println!("{:?}", end);
-    let sp = astar((0, 0), end, &cavern);
+    let sp = astar((0, 0), end, cavern)?
+        .ok_or_else(|| Error::invalid(Day15::DAY, "there is no path to the bottom right"))?;
     let mut risk = 0;
-    if let Some(sp) = sp {
-        for p in sp.iter().rev().skip(1) {
-            risk += cavern[p.0][p.1];
-        }
-        println!("{}", risk); //315 // 2998
-        
+    for p in sp.iter().rev().skip(1) {
+        risk += cavern[*p];
     }
-    risk as usize
+    debug!("{}", risk); //315 // 2998
+    Ok(risk as usize)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    #[test]
-    fn test_example() {
-        assert_eq!(solve(true, true), 40);
-        assert_eq!(solve(true, false), 315);
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Chiton";
+    type Input = Grid<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(Self::DAY, input, |c| c.to_digit(10).map(u64::from))
+    }
+
+    fn part1(cavern: &Self::Input) -> Result<Answer, Error> {
+        lowest_risk(cavern).map(Answer::from)
     }
-    #[test]
 
-    fn test_problem() {
-        assert_eq!(solve(false, true), 790);
-        assert_eq!(solve(false, false), 2998);
+    fn part2(cavern: &Self::Input) -> Result<Answer, Error> {
+        lowest_risk(&extend(cavern)).map(Answer::from)
     }
 }
\ No newline at end of file
@@ -1,32 +1,49 @@
 use std::vec::IntoIter;
 
-use crate::utils::read_challenge_data;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use tracing::debug;
+
+/// Most operator packets nested in each other. The packets are read, summed and dropped
+/// recursively, deeper packets could overflow the stack
+const MAX_DEPTH: usize = 100;
 
 #[derive(Clone, Debug)]
-struct Packet {
+pub struct Packet {
     version: u8,
     type_id: u8,
     packet_type: PacketType,
     //num_bits: u64,
 }
 impl Packet {
-    /// First read the version and type ID then decide what to read next based on type_id
-    fn read_packet(bv_iter: &mut IntoIter<u8>) -> Self {
-        let version = n_bits(bv_iter, 3).unwrap() as u8; // bv_iter.n_bits(3) as u8;
-        let type_id = n_bits(bv_iter, 3).unwrap() as u8; //bv_iter.n_bits(3) as u8;
+    /// First read the version and type ID then decide what to read next based on type_id.
+    /// Returns `None` if the bits run out, the packet is malformed or nested deeper than `MAX_DEPTH`
+    fn read_packet(bv_iter: &mut IntoIter<u8>, depth: usize) -> Option<Self> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let version = n_bits(bv_iter, 3)? as u8; // bv_iter.n_bits(3) as u8;
+        let type_id = n_bits(bv_iter, 3)? as u8; //bv_iter.n_bits(3) as u8;
         let packet_type = if type_id == 4 {
-            PacketType::LiteralPacketEnum(LiteralPacket::from_iter(bv_iter))
+            PacketType::LiteralPacketEnum(LiteralPacket::from_iter(bv_iter)?)
         } else {
-            PacketType::OperatorPacketEnum(OperatorPacket::from_iter(bv_iter))
+            let p = OperatorPacket::from_iter(bv_iter, depth)?;
+            // Comparisons need exactly 2 subpackets, the others at least one
+            let expected = if type_id >= 5 { 2..=2 } else { 1..=usize::MAX };
+            if !expected.contains(&p.subpackets.len()) {
+                return None;
+            }
+            PacketType::OperatorPacketEnum(p)
         };
-        Self {
+        Some(Self {
             version,
             type_id,
             packet_type,
-        }
+        })
     }
 
-    fn version_sum(&self) -> u64 {
+    pub fn version_sum(&self) -> u64 {
         let mut v = self.version as u64;
         if let PacketType::OperatorPacketEnum(op_packet) = &self.packet_type {
             for p in op_packet.subpackets.iter() {
@@ -46,7 +63,7 @@ impl Packet {
         n
     }
     /// Computes the value as required
-    fn value(&self) -> u64 {
+    pub fn value(&self) -> u64 {
         assert!(self.type_id <= 7);
         match &self.packet_type {
             PacketType::LiteralPacketEnum(p) => p.value,
@@ -80,22 +97,26 @@ struct LiteralPacket {
 }
 
 impl LiteralPacket {
-    fn from_iter(bv_iter: &mut IntoIter<u8>) -> Self {
+    fn from_iter(bv_iter: &mut IntoIter<u8>) -> Option<Self> {
         let mut num_bits = 6; // version and type_id
         let mut hex_digits = Vec::new();
         loop {
-            let bits = n_bits(bv_iter, 5).unwrap(); // bv_iter.n_bits(5);
+            let bits = n_bits(bv_iter, 5)?; // bv_iter.n_bits(5);
             hex_digits.push(bits & 0xf);
             num_bits += 5;
             if bits & 0x10 == 0 {
                 break;
             }
         }
+        // The value has to fit in a u64
+        if hex_digits.len() > 16 {
+            return None;
+        }
         let mut value = 0;
         for (i, &d) in hex_digits.iter().rev().enumerate() {
-            value += (d as u64) << (i * 4);
+            value += d << (i * 4);
         }
-        Self { num_bits, value }
+        Some(Self { num_bits, value })
     }
     fn num_bits(&self) -> u64 {
         self.num_bits
@@ -109,28 +130,29 @@ struct OperatorPacket {
 }
 
 impl OperatorPacket {
-    fn from_iter(bv_iter: &mut IntoIter<u8>) -> Self {
-        let length_type_id = n_bits(bv_iter, 1).unwrap() as u8; //bv_iter.n_bits(1) as u8;
+    /// `depth` is the one of the operator packet, the subpackets are one deeper
+    fn from_iter(bv_iter: &mut IntoIter<u8>, depth: usize) -> Option<Self> {
+        let length_type_id = n_bits(bv_iter, 1)? as u8; //bv_iter.n_bits(1) as u8;
         let mut subpackets: Vec<Packet> = Vec::new();
         if length_type_id == 0 {
-            let mut total_length = n_bits(bv_iter, 15).unwrap(); // bv_iter.n_bits(15);
+            let mut total_length = n_bits(bv_iter, 15)?; // bv_iter.n_bits(15);
             while total_length > 0 {
-                let p = Packet::read_packet(bv_iter);
-                total_length -= p.num_bits();
+                let p = Packet::read_packet(bv_iter, depth + 1)?;
+                total_length = total_length.checked_sub(p.num_bits())?;
                 subpackets.push(p);
             }
         } else {
-            let mut num_sub_packets = n_bits(bv_iter, 11).unwrap(); // bv_iter.n_bits(11);
+            let mut num_sub_packets = n_bits(bv_iter, 11)?; // bv_iter.n_bits(11);
             while num_sub_packets > 0 {
-                let p = Packet::read_packet(bv_iter);
+                let p = Packet::read_packet(bv_iter, depth + 1)?;
                 num_sub_packets -= 1;
                 subpackets.push(p);
             }
         }
-        Self {
+        Some(Self {
             length_type_id,
             subpackets,
-        }
+        })
     }
 
     fn num_bits(&self) -> u64 {
@@ -212,58 +234,69 @@ fn n_bits(iter: &mut IntoIter<u8>, n: usize) -> Option<u64> {
     }
     Some(v)
 }
-fn to_bit_vector(s: &str) -> Vec<u8> {
+fn to_bit_vector(s: &str) -> Result<Vec<u8>, Error> {
     let mut bv: Vec<u8> = Vec::new();
-    let s: Vec<u8> = s.chars().map(|e| e.to_digit(16).unwrap() as u8).collect();
-    for h in s {
+    for (j, e) in s.chars().enumerate() {
+        let h = e.to_digit(16).ok_or_else(|| {
+            Error::parse(
+                Day16::DAY,
+                1,
+                j + 1,
+                format!("{:?} is not a hexadecimal digit", e),
+            )
+        })? as u8;
         for i in 0..4 {
             let v = (h >> (3 - i)) & 1;
             bv.push(v);
         }
     }
-    bv
+    Ok(bv)
 }
 /// first 3 bits = type ID
 /// type ID 4 = litteral value -- single binary number.
 /// -  Pad with 0 until length is multiple of 4
 /// - Break up into groups of 4 bits
 /// - Each groups is prefixed by 1 except the last one which is prefixed by 0
+///
 /// type ID != 4 => operator
 /// - Has a length type id
 ///     - 0 -> 15 bits = total length of the subpackets
 ///     - 1 -> 11 bits = number of sub packets contained
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(16, run_example);
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+    /// The outermost packet
+    type Input = Packet;
 
-    let bv = to_bit_vector(&input);
-    let mut bv_iter = bv.into_iter();
-    //println!("{:?}", bv_iter);
-    //let bv = BitVec::from_hex(&input);
-    //println!("{:?}", bv);
-    // let mut bv_iter = bv.into_iter();
-    let p = Packet::read_packet(&mut bv_iter);
-    println!("{:?}", p);
-    if part1 {
-        println!("{}", p.version_sum()); // 901
-        p.version_sum() as usize
-    } else {
-        println!("{}", p.value()); // 110434737925
-        p.value() as usize
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let bv = to_bit_vector(input.trim())?;
+        let mut bv_iter = bv.into_iter();
+        //println!("{:?}", bv_iter);
+        //let bv = BitVec::from_hex(&input);
+        //println!("{:?}", bv);
+        // let mut bv_iter = bv.into_iter();
+        let p = Packet::read_packet(&mut bv_iter, 0).ok_or_else(|| {
+            Error::parse(
+                Self::DAY,
+                1,
+                input.trim().len() + 1 - bv_iter.len() / 4,
+                "malformed, truncated or too deeply nested packet",
+            )
+        })?;
+        debug!(packet = ?p);
+        //println!("After {:?}", bv_iter);
+        Ok(p)
     }
 
-    //println!("After {:?}", bv_iter);
-}
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    // #[test]
-    // // fn test_example() {
-    // //     assert_eq!(solve(true, true), 31);
-    // //     assert_eq!(solve(true, false), 31);
-    // // }
-    #[test]
-    fn test_problem() {
-        assert_eq!(solve(false, true), 901);
-        assert_eq!(solve(false, false), 110434737925);
+    fn part1(p: &Self::Input) -> Result<Answer, Error> {
+        debug!("{}", p.version_sum()); // 901
+        Ok(Answer::from(p.version_sum()))
+    }
+
+    fn part2(p: &Self::Input) -> Result<Answer, Error> {
+        debug!("{}", p.value()); // 110434737925
+        Ok(Answer::from(p.value()))
     }
 }
@@ -0,0 +1,214 @@
+use std::ops::{Add, AddAssign};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use tracing::debug;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Vector2 {
+    x: i64,
+    y: i64,
+}
+impl Add for Vector2 {
+    type Output = Vector2;
+    fn add(self, other: Vector2) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, other: Vector2) {
+        *self = *self + other;
+    }
+}
+struct Probe {
+    position: Vector2,
+    velocity: Vector2,
+}
+impl Probe {
+    fn update(&mut self) {
+        self.position += self.velocity;
+        self.velocity.x -= self.velocity.x.signum(); // signum gets the sign of the number
+        self.velocity.y -= 1; // gravity
+    }
+}
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rectangle {
+    top_left_corner: Vector2,
+    bottom_right_corner: Vector2,
+}
+impl Rectangle {
+    /// The `x=<x1>..<x2>` bounds of the input
+    pub fn x_range(&self) -> (i64, i64) {
+        (self.top_left_corner.x, self.bottom_right_corner.x)
+    }
+    /// The `y=<y1>..<y2>` bounds of the input
+    pub fn y_range(&self) -> (i64, i64) {
+        (self.bottom_right_corner.y, self.top_left_corner.y)
+    }
+    /// Checks if a projectile is in the area. Border included
+    fn contains(&self, point: &Vector2) -> bool {
+        let Vector2 { x, y } = *point;
+        x >= self.top_left_corner.x
+            && x <= self.bottom_right_corner.x
+            && y <= self.top_left_corner.y
+            && y >= self.bottom_right_corner.y
+    }
+    /// Checks if a projectile passed the rectangle
+    fn passed(&self, probe: &Probe) -> bool {
+        (probe.position.y < self.bottom_right_corner.y && probe.velocity.y < 0)
+            || (probe.position.x > self.bottom_right_corner.x && probe.velocity.x > 0)
+            || (probe.position.x < self.top_left_corner.x && probe.velocity.x < 0)
+        //true;
+    }
+}
+
+/// Checks if a probe will hit the area
+fn max_height(probe: Probe, target_area: &Rectangle) -> Option<i64> {
+    let mut probe = probe;
+    let mut y_max = i64::MIN;
+    loop {
+        y_max = y_max.max(probe.position.y);
+        if target_area.contains(&probe.position) {
+            return Some(y_max);
+        }
+        if target_area.passed(&probe) {
+            return None;
+        }
+        probe.update();
+    }
+}
+
+/// Tries all the velocities.
+/// Returns the highest y position reached and the number of velocities that hit the target
+fn launch(target_area: &Rectangle) -> Result<(i64, usize), Error> {
+    let y_limit = target_area.bottom_right_corner.y.abs();
+
+    let mut y_max = 0;
+    let mut c = 0;
+
+    // brute force the x values
+    for x in -1000..1000 {
+        cancel::checkpoint(Day17::DAY)?;
+        for y in -y_limit..y_limit {
+            let probe = Probe {
+                position: Vector2 { x: 0, y: 0 },
+                velocity: Vector2 { x, y },
+            };
+            if let Some(possible_max) = max_height(probe, target_area) {
+                y_max = y_max.max(possible_max);
+                c += 1;
+            }
+        }
+    }
+    Ok((y_max, c))
+}
+
+/// Bounds of the coordinates of the target area.
+/// The probes launched at farther areas would fly higher than an `i64`
+const MAX_COORDINATE: i64 = 1_000_000;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Trick Shot";
+    /// The target area
+    type Input = Rectangle;
+
+    // Parsing is pain
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        // Parse fiesta
+        let line = input.trim();
+        let err = || {
+            Error::parse(
+                Self::DAY,
+                1,
+                1,
+                format!(
+                    "expected `target area: x=<x1>..<x2>, y=<y1>..<y2>`, got {:?}",
+                    line
+                ),
+            )
+        };
+        // Parses `<a>..<b>`
+        let range = |s: &str| -> Result<(i64, i64), Error> {
+            let (a, b) = s.trim().splitn(2, "..").collect_tuple().ok_or_else(err)?;
+            let coordinate = |c: &str| {
+                let n = c.parse::<i64>().map_err(|e| {
+                    Error::parse(
+                        Self::DAY,
+                        1,
+                        column(line, c),
+                        format!("invalid coordinate {:?}: {}", c, e),
+                    )
+                })?;
+                if !(-MAX_COORDINATE..=MAX_COORDINATE).contains(&n) {
+                    return Err(Error::parse(
+                        Self::DAY,
+                        1,
+                        column(line, c),
+                        format!("coordinate {} is farther than {} from 0", n, MAX_COORDINATE),
+                    ));
+                }
+                Ok(n)
+            };
+            Ok((coordinate(a)?, coordinate(b)?))
+        };
+        let (x_range, y_range) = line
+            .strip_prefix("target area: x=")
+            .and_then(|s| s.splitn(2, ", y=").collect_tuple())
+            .ok_or_else(err)?;
+        let ((x1, x2), (y1, y2)) = (range(x_range)?, range(y_range)?);
+        if x1 > x2 || y1 > y2 {
+            return Err(Error::parse(
+                Self::DAY,
+                1,
+                1,
+                "the ranges must be increasing",
+            ));
+        }
+        if y2 >= 0 {
+            return Err(Error::invalid(
+                Self::DAY,
+                "the target area must be below the submarine",
+            ));
+        }
+
+        Ok(Rectangle {
+            top_left_corner: Vector2 { x: x1, y: y2 },
+            bottom_right_corner: Vector2 { x: x2, y: y1 },
+        })
+
+        // Parsing is pain
+        // let target_area = if run_example {
+        //     Rectangle {
+        //         top_left_corner: Vector2 { x: 20, y: -5 },
+        //         bottom_right_corner: Vector2 { x: 30, y: -10 },
+        //     }
+        // } else {
+        //     Rectangle {
+        //         top_left_corner: Vector2 { x: 138, y: -71 },
+        //         bottom_right_corner: Vector2 { x: 184, y: -125 },
+        //     }
+        // };
+    }
+
+    fn part1(target_area: &Self::Input) -> Result<Answer, Error> {
+        let (y_max, _) = launch(target_area)?;
+        debug!("{}", y_max); //7750
+        Ok(Answer::from(y_max))
+    }
+
+    fn part2(target_area: &Self::Input) -> Result<Answer, Error> {
+        let (_, c) = launch(target_area)?;
+        debug!("{}", c); // 4120
+        Ok(Answer::from(c))
+    }
+}
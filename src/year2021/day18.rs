@@ -1,12 +1,17 @@
-use crate::utils::read_challenge_data;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use tracing::debug;
 
 /// If parent is on idx =>
 ///     left_child = (2 * idx) + 1
 ///     left_child = (2 * idx) + 2
+#[allow(unused)]
 struct Tree {
     values: Vec<i64>,
 }
 
+#[allow(unused)]
 impl Tree {
     fn insert(&mut self, value: i64, parent: usize, left: bool) {
         let pos = 2 * parent + if left { 1 } else { 2 };
@@ -23,6 +28,7 @@ impl Tree {
     }
 }
 
+#[allow(unused)]
 fn split_number(n: usize) -> (usize, usize) {
     if n & 1 == 1 {
         (n / 2, n / 2 + 1)
@@ -30,27 +36,27 @@ fn split_number(n: usize) -> (usize, usize) {
         (n / 2, n / 2)
     }
 }
+#[allow(unused)]
 fn explode() {}
 
-pub fn solve(run_example: bool, _part1: bool) -> usize {
-    let _input = read_challenge_data(18, run_example);
+pub struct Day18;
 
-    let i: usize = 3;
-    println!("{}", (i - 1) / 2);
-    0
-}
-#[cfg(test)]
-mod tests {
-
-    // #[test]
-    // fn test_example() {
-    //     assert_eq!(solve(true, true), 0);
-    //     assert_eq!(solve(true, false), 0);
-    // }
-    // #[test]
-
-    // fn test_problem() {
-    //     assert_eq!(solve(false, true), 0);
-    //     assert_eq!(solve(false, false), 0);
-    // }
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Snailfish";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer, Error> {
+        let i: usize = 3;
+        debug!("{}", (i - 1) / 2);
+        Ok(Answer::Int(0))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::Int(0))
+    }
 }
@@ -0,0 +1,141 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Add, AddAssign},
+};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use tracing::debug;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Vector3 {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Vector3 {
+    #[allow(unused)]
+    fn manhattan_distance(&self, other: &Vector3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    fn euclidean_distance_squared(&self, other: &Vector3) -> i64 {
+        (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)
+    }
+}
+impl Add for Vector3 {
+    type Output = Vector3;
+    fn add(self, other: Vector3) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, other: Vector3) {
+        *self = *self + other;
+    }
+}
+
+/// Distances between the beacons seen by different scanners
+fn beacons_distances(scanners: &HashMap<String, Vec<Vector3>>) -> Result<usize, Error> {
+    let mut beacons_distances = HashSet::new();
+    for (scanner1, beacons1) in scanners {
+        cancel::checkpoint(Day19::DAY)?;
+        for (scanner2, beacons2) in scanners {
+            if scanner1 != scanner2 {
+                for beacon1 in beacons1 {
+                    for beacon2 in beacons2 {
+                        let d = beacon1.euclidean_distance_squared(beacon2);
+                        beacons_distances.insert(d);
+                    }
+                }
+            }
+        }
+    }
+    //println!("{:?}", beacons_distances);
+    debug!("{}", beacons_distances.len());
+
+    Ok(0)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+    /// The beacons seen by each scanner
+    type Input = HashMap<String, Vec<Vector3>>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut scanners: HashMap<String, Vec<Vector3>> = HashMap::new();
+        let mut current_scanner = None;
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() || line == "\n" || line == "\r\n" {
+                continue;
+            } else if line.starts_with("--") {
+                let scanner_name = line
+                    .trim()
+                    .trim_start_matches("--- ")
+                    .trim_end_matches(" ---")
+                    .to_string();
+                scanners.insert(scanner_name, Vec::new());
+                current_scanner = Some(line.to_string());
+            } else if let Some(sc) = &current_scanner {
+                let (x, y, z) = line
+                    .trim()
+                    .split(',')
+                    .map(|s| {
+                        s.parse::<i64>().map_err(|e| {
+                            Error::parse(
+                                Self::DAY,
+                                i + 1,
+                                column(line, s),
+                                format!("invalid coordinate {:?}: {}", s, e),
+                            )
+                        })
+                    })
+                    .collect_tuple()
+                    .ok_or_else(|| {
+                        Error::parse(
+                            Self::DAY,
+                            i + 1,
+                            1,
+                            format!("expected `<x>,<y>,<z>`, got {:?}", line),
+                        )
+                    })?;
+                let v = Vector3 {
+                    x: x?,
+                    y: y?,
+                    z: z?,
+                };
+                scanners.entry(sc.clone()).and_modify(|e| e.push(v));
+            } else {
+                return Err(Error::parse(
+                    Self::DAY,
+                    i + 1,
+                    1,
+                    "beacon found before any scanner",
+                ));
+            }
+        }
+
+        //println!("{:?}", scanners);
+        Ok(scanners)
+    }
+
+    fn part1(scanners: &Self::Input) -> Result<Answer, Error> {
+        beacons_distances(scanners).map(Answer::from)
+    }
+
+    fn part2(scanners: &Self::Input) -> Result<Answer, Error> {
+        beacons_distances(scanners).map(Answer::from)
+    }
+}
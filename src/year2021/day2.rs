@@ -0,0 +1,309 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::answer::Answer;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use crate::utils::csv_string;
+use tracing::debug;
+
+/// A line of the course, `<direction> <distance>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Forward(isize),
+    Down(isize),
+    Up(isize),
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    /// Parses a single command, errors are reported on line 1
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        let (direction, distance) = command
+            .split_once(' ')
+            .ok_or_else(|| Error::parse(Day2::DAY, 1, 1, "expected `<direction> <distance>`"))?;
+        let distance = distance.trim().parse().map_err(|e| {
+            Error::parse(
+                Day2::DAY,
+                1,
+                column(command, distance),
+                format!("invalid distance {:?}: {}", distance, e),
+            )
+        })?;
+        match direction {
+            "forward" => Ok(Command::Forward(distance)),
+            "down" => Ok(Command::Down(distance)),
+            "up" => Ok(Command::Up(distance)),
+            _ => Err(Error::parse(
+                Day2::DAY,
+                1,
+                1,
+                format!("invalid direction {:?}", direction),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(distance) => write!(f, "forward {}", distance),
+            Command::Down(distance) => write!(f, "down {}", distance),
+            Command::Up(distance) => write!(f, "up {}", distance),
+        }
+    }
+}
+
+/// Submarine struct to handle the movement and position
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Submarine {
+    /// Horizontal position
+    pub x: isize,
+    /// Vertical position, increases with depth
+    pub y: isize,
+    pub aim: isize,
+}
+
+impl Submarine {
+    pub fn new(x: isize, y: isize, aim: isize) -> Self {
+        Self { x, y, aim }
+    }
+
+    /// Follows the commands, moving as `model` says
+    pub fn run<'a>(
+        &mut self,
+        model: &impl MovementModel,
+        commands: impl IntoIterator<Item = &'a Command>,
+    ) {
+        for &command in commands {
+            model.apply(self, command);
+        }
+    }
+
+    /// Follows the commands like `run`, keeping the state after each of them
+    pub fn trajectory(self, model: &impl MovementModel, commands: &[Command]) -> Trajectory {
+        let mut submarine = self;
+        let mut steps = Vec::with_capacity(commands.len() + 1);
+        steps.push((None, submarine));
+        for &command in commands {
+            model.apply(&mut submarine, command);
+            steps.push((Some(command), submarine));
+        }
+        Trajectory { steps }
+    }
+
+    /// Getter for x, y
+    pub fn pos(&self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+}
+
+/// The path of the submarine: where it starts, then each command and the state it leads to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trajectory {
+    pub steps: Vec<(Option<Command>, Submarine)>,
+}
+
+impl Trajectory {
+    /// Deepest point reached, the start if it never goes down
+    pub fn max_depth(&self) -> isize {
+        self.states().map(|s| s.y).max().unwrap_or(0)
+    }
+
+    /// First step where the aim is negative, the submarine is pointing up
+    pub fn first_negative_aim(&self) -> Option<usize> {
+        self.states().position(|s| s.aim < 0)
+    }
+
+    fn states(&self) -> impl Iterator<Item = &Submarine> {
+        self.steps.iter().map(|(_, submarine)| submarine)
+    }
+
+    /// A header and a row for each step. The command is empty for the start
+    pub fn to_csv(&self) -> String {
+        let rows = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(step, (command, submarine))| {
+                [
+                    step.to_string(),
+                    command.map(|c| c.to_string()).unwrap_or_default(),
+                    submarine.x.to_string(),
+                    submarine.y.to_string(),
+                    submarine.aim.to_string(),
+                ]
+            });
+        csv_string(&["step", "command", "x", "y", "aim"], rows)
+    }
+
+    /// The positions as a polyline, depth going down like in the water
+    pub fn to_svg(&self) -> String {
+        let (min_x, max_x) = (
+            self.states().map(|s| s.x).min().unwrap_or(0),
+            self.states().map(|s| s.x).max().unwrap_or(0),
+        );
+        let (min_y, max_y) = (
+            self.states().map(|s| s.y).min().unwrap_or(0),
+            self.max_depth(),
+        );
+        // A margin so the line isn't cut at the borders, and a size even if it doesn't move
+        let margin = ((max_x - min_x).max(max_y - min_y) / 20).max(1);
+        let points: Vec<String> = self.states().map(|s| format!("{},{}", s.x, s.y)).collect();
+        format!(
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n",
+                "  <polyline points=\"{}\" fill=\"none\" stroke=\"black\" ",
+                "vector-effect=\"non-scaling-stroke\"/>\n",
+                "</svg>\n"
+            ),
+            min_x - margin,
+            min_y - margin,
+            max_x - min_x + 2 * margin,
+            max_y - min_y + 2 * margin,
+            points.join(" ")
+        )
+    }
+}
+
+/// How a command moves the submarine
+pub trait MovementModel {
+    fn apply(&self, submarine: &mut Submarine, command: Command);
+}
+
+/// Part 1: down and up change the depth directly
+pub struct Direct;
+
+impl MovementModel for Direct {
+    fn apply(&self, submarine: &mut Submarine, command: Command) {
+        match command {
+            Command::Forward(distance) => submarine.x += distance,
+            Command::Down(distance) => submarine.y += distance,
+            Command::Up(distance) => submarine.y -= distance,
+        }
+    }
+}
+
+/// Part 2: down and up change the aim, the depth changes when moving forward
+pub struct Aimed;
+
+impl MovementModel for Aimed {
+    fn apply(&self, submarine: &mut Submarine, command: Command) {
+        match command {
+            Command::Forward(distance) => {
+                submarine.x += distance;
+                submarine.y += submarine.aim * distance;
+            }
+            Command::Down(distance) => submarine.aim += distance,
+            Command::Up(distance) => submarine.aim -= distance,
+        }
+    }
+}
+
+/// Product of the final horizontal position and depth
+fn follow(commands: &[Command], model: &impl MovementModel) -> isize {
+    let mut submarine = Submarine::default();
+    submarine.run(model, commands);
+    let (x, y) = submarine.pos();
+    x * y
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Dive!";
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| line.parse().map_err(|e: Error| e.at_line(i + 1)))
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input) -> Result<Answer, Error> {
+        let product = follow(commands, &Direct);
+        debug!("{}", product); // 1990000
+        Ok(Answer::from(product))
+    }
+
+    fn part2(commands: &Self::Input) -> Result<Answer, Error> {
+        let product = follow(commands, &Aimed);
+        debug!("{}", product); // 1975421260
+        Ok(Answer::from(product))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aimed, Command, Day2, Direct, MovementModel, Submarine};
+    use crate::error::Error;
+    use crate::solution::Solution;
+
+    /// Like `Aimed`, but the submarine can't go deeper than `max_depth`
+    struct DepthLimited {
+        max_depth: isize,
+    }
+
+    impl MovementModel for DepthLimited {
+        fn apply(&self, submarine: &mut Submarine, command: Command) {
+            Aimed.apply(submarine, command);
+            submarine.y = submarine.y.clamp(0, self.max_depth);
+        }
+    }
+
+    #[test]
+    fn test_movement_models() {
+        assert_eq!("down 5".parse::<Command>().unwrap(), Command::Down(5));
+        assert!("sideways 5".parse::<Command>().is_err());
+        assert!("up five".parse::<Command>().is_err());
+        assert!(matches!(
+            Day2::parse("up 1\nforward").unwrap_err(),
+            Error::Parse { line: 2, .. }
+        ));
+
+        let commands =
+            Day2::parse("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n").unwrap();
+        let mut submarine = Submarine::default();
+        submarine.run(&Direct, &commands);
+        assert_eq!(submarine.pos(), (15, 10));
+        let mut submarine = Submarine::default();
+        submarine.run(&Aimed, &commands);
+        assert_eq!(submarine.pos(), (15, 60));
+        let mut submarine = Submarine::default();
+        submarine.run(&DepthLimited { max_depth: 50 }, &commands);
+        assert_eq!(submarine.pos(), (15, 50));
+    }
+    #[test]
+    fn test_trajectory() {
+        let commands =
+            Day2::parse("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n").unwrap();
+        let direct = Submarine::default().trajectory(&Direct, &commands);
+        assert_eq!(direct.steps.len(), 7);
+        assert_eq!(direct.steps[6].1.pos(), (15, 10));
+        assert_eq!(direct.max_depth(), 10);
+
+        let aimed = Submarine::default().trajectory(&Aimed, &commands);
+        assert_eq!(
+            aimed.steps[3],
+            (Some(Command::Forward(8)), Submarine::new(13, 40, 5))
+        );
+        assert_eq!(aimed.max_depth(), 60);
+        assert_eq!(aimed.first_negative_aim(), None);
+        let up = Submarine::default().trajectory(&Aimed, &[Command::Down(1), Command::Up(2)]);
+        assert_eq!(up.first_negative_aim(), Some(2));
+
+        let csv = aimed.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("step,command,x,y,aim"));
+        assert_eq!(lines.next(), Some("0,,0,0,0"));
+        assert_eq!(lines.last(), Some("6,forward 2,15,60,10"));
+
+        let svg = direct.to_svg();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("points=\"0,0 5,0 5,5 13,5 13,2 13,10 15,10\""));
+    }
+}
\ No newline at end of file
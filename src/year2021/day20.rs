@@ -0,0 +1,125 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::utils::Grid;
+use tracing::debug;
+
+/// Applies the algorithm once. The new image is one pixel bigger on every side,
+/// pixels outside of the old image have the `background` value
+fn generate_new_image(pixels: &Grid<bool>, algorithm: &[bool], background: bool) -> Grid<bool> {
+    Grid::from_fn(pixels.rows() + 2, pixels.cols() + 2, |(i, j)| {
+        // (i, j) is (i - 1, j - 1) in the old image
+        let (i, j) = (i as isize - 1, j as isize - 1);
+        let mut p = 0;
+        for di in [-1, 0, 1] {
+            for dj in [-1, 0, 1] {
+                let lit = pixels
+                    .checked((i + di, j + dj))
+                    .map_or(background, |pos| pixels[pos]);
+                p = (p << 1) + usize::from(lit);
+            }
+        }
+        algorithm[p]
+    })
+}
+
+#[allow(unused)]
+fn imshow(pixels: &Grid<bool>) {
+    debug!("{}", pixels.map(|&lit| if lit { '#' } else { '.' }));
+}
+
+/// The image enhancement algorithm and the pixels of the input image
+pub struct Image {
+    algorithm: Vec<bool>,
+    pixels: Grid<bool>,
+}
+
+/// Number of light pixels after enhancing the image `steps` times.
+/// The algorithm and the image are checked when parsing
+fn enhance(image: &Image, steps: usize) -> Result<usize, Error> {
+    let algorithm = &image.algorithm;
+    let mut pixels = image.pixels.clone();
+    // The infinite background starts dark. If algorithm[0] is '#' it flashes
+    // every step, unless algorithm[511] is '#' too and it stays lit
+    let mut background = false;
+
+    for step in 0..steps {
+        // Bounds of the current image, the input image starts at (0, 0)
+        let offset = step as isize;
+        debug!(
+            step,
+            top = -offset,
+            bottom = pixels.rows() as isize - 1 - offset,
+            left = -offset,
+            right = pixels.cols() as isize - 1 - offset,
+            "bounds"
+        );
+        pixels = generate_new_image(&pixels, algorithm, background);
+        background = algorithm[if background { 511 } else { 0 }];
+    }
+    if background {
+        return Err(Error::invalid(Day20::DAY, "infinitely many pixels are lit"));
+    }
+    let light_pixels = pixels.iter().filter(|(_, &lit)| lit).count();
+    debug!("{:?}", light_pixels);
+    Ok(light_pixels)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Trench Map";
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut lines = input.lines();
+        let algorithm = lines.next().unwrap_or_default().trim();
+        if algorithm.chars().count() != 512 {
+            return Err(Error::parse(
+                Self::DAY,
+                1,
+                1,
+                format!(
+                    "the algorithm has {} pixels instead of 512",
+                    algorithm.chars().count()
+                ),
+            ));
+        }
+        let algorithm = algorithm
+            .chars()
+            .enumerate()
+            .map(|(j, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(Error::parse(
+                    Self::DAY,
+                    1,
+                    j + 1,
+                    "pixels must be `#` or `.`",
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        lines.next();
+
+        // The image starts on the third line
+        let pixels = Grid::parse_lines(
+            Self::DAY,
+            lines.enumerate().map(|(i, line)| (i + 3, line)),
+            |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+        )?;
+        Ok(Image { algorithm, pixels })
+    }
+
+    fn part1(image: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(enhance(image, 2)?))
+    }
+
+    fn part2(image: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(enhance(image, 50)?))
+    }
+}
@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 
-use itertools::Itertools;
-
-use crate::utils::{read_challenge_data, wrap};
+use crate::{
+    answer::Answer,
+    error::{column, Error},
+    solution::Solution,
+    utils::wrap,
+};
+use tracing::debug;
 
 struct Die {
     value: usize,
@@ -60,21 +64,51 @@ fn dp(state: State, memo: &mut HashMap<State, (usize, usize)>) -> (usize, usize)
     res
 }
 
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(21, run_example);
-    let (mut p1, mut p2) = input
-        .lines()
-        .map(|s| {
-            s.trim().trim_start_matches("Player ")[1..]
-                .trim_start_matches(" starting position: ")
-                .parse::<usize>()
-                .unwrap()
-        })
-        .tuples()
-        .next()
-        .unwrap();
-
-    if part1 {
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "Dirac Dice";
+    /// Starting positions of the 2 players
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut positions = input.lines().enumerate().map(|(i, line)| {
+            let err = || {
+                Error::parse(
+                    Self::DAY,
+                    i + 1,
+                    1,
+                    format!(
+                        "expected `Player {} starting position: <1-10>`, got {:?}",
+                        i + 1,
+                        line
+                    ),
+                )
+            };
+            let prefix = format!("Player {} starting position: ", i + 1);
+            let position = line.trim().strip_prefix(prefix.as_str()).ok_or_else(err)?;
+            match position.parse::<usize>() {
+                Ok(p) if (1..=10).contains(&p) => Ok(p),
+                _ => Err(Error::parse(
+                    Self::DAY,
+                    i + 1,
+                    column(line, position),
+                    format!("invalid starting position {:?}", position),
+                )),
+            }
+        });
+        match (positions.next(), positions.next(), positions.next()) {
+            (Some(p1), Some(p2), None) => Ok((p1?, p2?)),
+            _ => Err(Error::invalid(
+                Self::DAY,
+                "the game needs exactly 2 players",
+            )),
+        }
+    }
+
+    fn part1(&(p1, p2): &Self::Input) -> Result<Answer, Error> {
+        let (mut p1, mut p2) = (p1, p2);
         let (mut score1, mut score2) = (0, 0);
         let mut p1_turn = true;
         let mut ddie = Die { value: 0 };
@@ -99,35 +133,22 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
         } else {
             score1 * total_rolls
         };
-        println!("{}", c);
-        return c;
+        debug!("{}", c);
+        Ok(Answer::from(c))
     }
 
-    // memoization table
-    let mut memo = HashMap::new();
+    fn part2(&(p1, p2): &Self::Input) -> Result<Answer, Error> {
+        // memoization table
+        let mut memo = HashMap::new();
 
-    let state = State {
-        positions: [p1, p2],
-        scores: [0, 0],
-        turn: 0,
-    };
-    let res = dp(state, &mut memo);
-
-    println!("{:?}", res.0.max(res.1));
-    res.0.max(res.1)
-}
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    #[test]
-    fn test_example() {
-        assert_eq!(solve(true, true), 739785);
-        assert_eq!(solve(true, false), 444356092776315);
-    }
-    #[test]
+        let state = State {
+            positions: [p1, p2],
+            scores: [0, 0],
+            turn: 0,
+        };
+        let res = dp(state, &mut memo);
 
-    fn test_problem() {
-        assert_eq!(solve(false, true), 598416);
-        assert_eq!(solve(false, false), 27674034218179);
+        debug!("{:?}", res.0.max(res.1));
+        Ok(Answer::from(res.0.max(res.1)))
     }
 }
@@ -0,0 +1,211 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use tracing::{debug, trace};
+
+/// x, y, z store the bounds min and max
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Cuboid {
+    on: bool,
+    x: (isize, isize),
+    y: (isize, isize),
+    z: (isize, isize),
+}
+
+impl Cuboid {
+    /// If the step turns the cubes on
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+
+    /// The x, y and z bounds, both included
+    pub fn ranges(&self) -> [(isize, isize); 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+/// Bounds of the coordinates of the cuboids.
+/// The volumes of bigger cuboids would overflow an `isize`
+const MAX_COORDINATE: isize = 1_000_000;
+
+type Step = (bool, (isize, isize), (isize, isize), (isize, isize));
+/// Parse one reboot step. `line` is the line number of the step, used for errors
+fn parse(line: usize, s: &str) -> Result<Step, Error> {
+    let err = || {
+        Error::parse(
+            Day22::DAY,
+            line,
+            1,
+            format!(
+                "expected `on|off x=<a>..<b>,y=<a>..<b>,z=<a>..<b>`, got {:?}",
+                s
+            ),
+        )
+    };
+    let (on, ranges) = if let Some(ranges) = s.trim().strip_prefix("on ") {
+        (true, ranges)
+    } else if let Some(ranges) = s.trim().strip_prefix("off ") {
+        (false, ranges)
+    } else {
+        return Err(err());
+    };
+
+    let mut t = ranges
+        .splitn(3, ',')
+        .zip(["x=", "y=", "z="])
+        .map(|(ss, axis)| {
+            let ss = ss.strip_prefix(axis).ok_or_else(err)?;
+            let (a, b) = ss
+                .splitn(2, "..")
+                .map(|sss| {
+                    let n = sss.trim().parse::<isize>().map_err(|e| {
+                        Error::parse(
+                            Day22::DAY,
+                            line,
+                            column(s, sss),
+                            format!("invalid coordinate {:?}: {}", sss, e),
+                        )
+                    })?;
+                    if !(-MAX_COORDINATE..=MAX_COORDINATE).contains(&n) {
+                        return Err(Error::parse(
+                            Day22::DAY,
+                            line,
+                            column(s, sss),
+                            format!("coordinate {} is farther than {} from 0", n, MAX_COORDINATE),
+                        ));
+                    }
+                    Ok(n)
+                })
+                .collect_tuple()
+                .ok_or_else(err)?;
+            let (a, b) = (a?, b?);
+            if a > b {
+                return Err(Error::parse(
+                    Day22::DAY,
+                    line,
+                    column(s, ss),
+                    "the range must be increasing",
+                ));
+            }
+            Ok((a, b))
+        });
+    let xs = t.next().ok_or_else(err)??;
+    let ys = t.next().ok_or_else(err)??;
+    let zs = t.next().ok_or_else(err)??;
+    Ok((on, xs, ys, zs))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    const TITLE: &'static str = "Reactor Reboot";
+    /// The reboot steps
+    type Input = Vec<Cuboid>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (on, x, y, z) = parse(i + 1, line)?;
+                Ok(Cuboid { on, x, y, z })
+            })
+            .collect()
+    }
+
+    /// Naive part 1
+    fn part1(cuboids: &Self::Input) -> Result<Answer, Error> {
+        // brute force the cubes
+        let mut cubes_on = HashSet::new();
+        for &Cuboid { on, x, y, z } in cuboids {
+            cancel::checkpoint(Self::DAY)?;
+            trace!(on, ?x, ?y, ?z, "cuboid");
+            let x = (x.0.max(-50), x.1.min(50));
+            let y = (y.0.max(-50), y.1.min(50));
+            let z = (z.0.max(-50), z.1.min(50));
+            for xi in x.0..x.1 + 1 {
+                for yi in y.0..y.1 + 1 {
+                    for zi in z.0..z.1 + 1 {
+                        if on {
+                            cubes_on.insert((xi, yi, zi));
+                        } else {
+                            cubes_on.remove(&(xi, yi, zi));
+                        }
+                    }
+                }
+            }
+        }
+        debug!("{:?}", cubes_on.len()); //567496
+        Ok(Answer::from(cubes_on.len()))
+    }
+
+    /// Using coordinate compression
+    /// Segments: [1, 10], [5, 15], [20, 30] -> [1, 3], [2, 4], [5, 6]
+    /// Then we do the brute force approach on these
+    /// https://stackoverflow.com/questions/29528934/coordinate-compression
+    fn part2(cuboids: &Self::Input) -> Result<Answer, Error> {
+        let mut cubes_on = HashSet::new();
+        let mut x_coords = Vec::new();
+        let mut y_coords = Vec::new();
+        let mut z_coords = Vec::new();
+        for cuboid in cuboids {
+            x_coords.push(cuboid.x.0);
+            x_coords.push(cuboid.x.1 + 1);
+            y_coords.push(cuboid.y.0);
+            y_coords.push(cuboid.y.1 + 1);
+            z_coords.push(cuboid.z.0);
+            z_coords.push(cuboid.z.1 + 1);
+        }
+
+        x_coords.sort_unstable();
+        y_coords.sort_unstable();
+        z_coords.sort_unstable();
+
+        // Compress -- form a map from the coord to the index in the array
+        let x_compressed: HashMap<isize, usize> =
+            x_coords.iter().enumerate().map(|(i, &e)| (e, i)).collect();
+        let y_compressed: HashMap<isize, usize> =
+            y_coords.iter().enumerate().map(|(i, &e)| (e, i)).collect();
+        let z_compressed: HashMap<isize, usize> =
+            z_coords.iter().enumerate().map(|(i, &e)| (e, i)).collect();
+
+        // Same solution as part 1 but now we use smaller coords
+        // so it will finish in reasonable time
+        for cuboid in cuboids {
+            trace!(?cuboid);
+            for xi in *x_compressed.get(&cuboid.x.0).unwrap()
+                ..*x_compressed.get(&(cuboid.x.1 + 1)).unwrap()
+            {
+                // A big cuboid alone can cover most of the compressed space
+                cancel::checkpoint(Self::DAY)?;
+                for yi in *y_compressed.get(&cuboid.y.0).unwrap()
+                    ..*y_compressed.get(&(cuboid.y.1 + 1)).unwrap()
+                {
+                    for zi in *z_compressed.get(&cuboid.z.0).unwrap()
+                        ..*z_compressed.get(&(cuboid.z.1 + 1)).unwrap()
+                    {
+                        if cuboid.on {
+                            cubes_on.insert((xi, yi, zi));
+                        } else {
+                            cubes_on.remove(&(xi, yi, zi));
+                        }
+                    }
+                }
+            }
+        }
+        let mut res = 0;
+        for (xi, yi, zi) in cubes_on {
+            res += (x_coords[xi + 1] - x_coords[xi])
+                * (y_coords[yi + 1] - y_coords[yi])
+                * (z_coords[zi + 1] - z_coords[zi]);
+        }
+        debug!("{}", res); //1355961721298916 -- takes a while
+        Ok(Answer::from(res))
+    }
+}
\ No newline at end of file
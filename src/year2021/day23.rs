@@ -0,0 +1,23 @@
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "Amphipod";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::Int(0))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::Int(0))
+    }
+}
\ No newline at end of file
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use crate::utils::read_challenge_data;
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::Error;
+use crate::solution::Solution;
+use tracing::{debug, trace};
 
 /// Magic numbers from the algorithm
 const MAGIC_ADD_X: [isize; 14] = [11, 13, 11, 10, -3, -4, 12, -8, -3, -12, 14, -6, 11, -12];
@@ -16,9 +20,8 @@ fn eval_operand(operand: &str, registers: &HashMap<char, isize>) -> isize {
         operand.parse::<isize>().unwrap()
     }
 }
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let _input = read_challenge_data(24, run_example);
-
+/// Searches the largest (part 1) or smallest (part 2) model number accepted by MONAD
+fn find_model_number(part1: bool) -> Result<i64, Error> {
     // Failed attempt to parse instructions and execute them
     // let mut number = 100_000_000_000_000u64.to_string();
     // loop {
@@ -129,10 +132,16 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
 
     let mut w = [0; 14];
     let mut number = if part1 { 10_000_000 } else { 1_000_000 };
-    'outer: loop {
+    // The last combination to try, all 1's or all 9's
+    let last = if part1 { 1_111_111 } else { 9_999_999 };
+    'outer: while number != last {
         // We use number to go over possible 7-digit combinations.
         // This is a substitute for a cartesian product of 7 numbers
         number -= if part1 { 1 } else { -1 };
+        // Looking at the token for every combination would slow the search down
+        if number % 10_000 == 0 {
+            cancel::checkpoint(Day24::DAY)?;
+        }
         let number_string = number.to_string();
         // Check for 0's
         if number_string.chars().any(|c| c == '0') {
@@ -171,30 +180,36 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
             z += x * (w[i] + MAGIC_ADD_Y[i]);
         }
         if z == 0 {
-            let res = w
-                .into_iter()
-                .map(|s| s.to_string())
-                .collect::<String>()
-                .parse::<usize>()
-                .unwrap();
-            println!("found: {}", res); //74929995999389
-            return res;
+            // The digits are all between 1 and 9, 14 of them fit in an i64
+            let res = w.iter().fold(0, |acc, &d| acc * 10 + d as i64);
+            debug!("found: {}", res); //74929995999389
+            return Ok(res);
         } else {
-            println!("{:?}", w);
+            trace!(digits = ?w, "rejected");
         }
     }
+    Err(Error::invalid(
+        Day24::DAY,
+        "no model number is accepted by MONAD",
+    ))
 }
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    // #[test]
-    // fn test_example() {
-    //     assert_eq!(solve(true, true), 7);
-    //     assert_eq!(solve(true, false), 5);
-    // }
-    #[test]
-    fn test_problem() {
-        assert_eq!(solve(false, true), 74929995999389);
-        assert_eq!(solve(false, false), 11118151637112);
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Arithmetic Logic Unit";
+    /// The MONAD program. The magic numbers are read from it by hand
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer, Error> {
+        find_model_number(true).map(Answer::from)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer, Error> {
+        find_model_number(false).map(Answer::from)
     }
 }
@@ -0,0 +1,97 @@
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::utils::Grid;
+use tracing::debug;
+
+/// A spot on the sea floor, empty or with a sea cucumber of one of the 2 herds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+/// Same characters as the input
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::East => '>',
+            Cell::South => 'v',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+/// Positions of the 2 herds of sea cucumbers
+pub type SeaFloor = Grid<Cell>;
+
+/// Moves all the cucumbers of a herd at the same time, wrapping around the edges.
+/// Returns if any of them moved
+fn move_herd(sea_floor: &mut SeaFloor, herd: Cell, direction: (isize, isize)) -> bool {
+    let current = sea_floor.clone();
+    let mut has_moved = false;
+    for (pos, &cell) in current.iter() {
+        if cell != herd {
+            continue;
+        }
+        // If it's occupied don't move
+        let next_pos = current.wrapping_step(pos, direction);
+        if current[next_pos] == Cell::Empty {
+            sea_floor[pos] = Cell::Empty;
+            sea_floor[next_pos] = herd;
+            has_moved = true;
+        }
+    }
+    has_moved
+}
+
+/// Number of steps until the sea cucumbers stop moving
+fn steps_until_stop(sea_floor: &SeaFloor) -> usize {
+    let mut sea_floor = sea_floor.clone();
+
+    // print map
+    // println!("{}", sea_floor);
+
+    let mut c = 0;
+    loop {
+        c += 1;
+        // The east herd moves first, then the south herd
+        let east_moved = move_herd(&mut sea_floor, Cell::East, (0, 1));
+        let south_moved = move_herd(&mut sea_floor, Cell::South, (1, 0));
+
+        if !east_moved && !south_moved {
+            break;
+        }
+    }
+    debug!("{}", c); // 504
+    c
+}
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Sea Cucumber";
+    type Input = SeaFloor;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(Self::DAY, input, |c| match c {
+            '>' => Some(Cell::East),
+            'v' => Some(Cell::South),
+            '.' => Some(Cell::Empty),
+            _ => None,
+        })
+    }
+
+    fn part1(sea_floor: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(steps_until_stop(sea_floor)))
+    }
+
+    /// There is no part 2 on the last day
+    fn part2(sea_floor: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(steps_until_stop(sea_floor)))
+    }
+}
@@ -0,0 +1,338 @@
+use std::str::FromStr;
+
+use num::{BigInt, BigUint};
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use tracing::debug;
+
+/// A diagnostic of any width.
+/// Bit 0 is the most significant one, the first character of the line.
+/// The words keep the bits in that order from their high end, so diagnostics
+/// of the same width are ordered like the numbers
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bits {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl Bits {
+    /// `len` bits set to 0
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Panics if `i` is out of bounds, like indexing
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {} out of {}", i, self.len);
+        (self.words[i / 64] >> (63 - i % 64)) & 1 == 1
+    }
+
+    /// Panics if `i` is out of bounds, like indexing
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "bit {} out of {}", i, self.len);
+        let mask = 1 << (63 - i % 64);
+        if value {
+            self.words[i / 64] |= mask;
+        } else {
+            self.words[i / 64] &= !mask;
+        }
+    }
+
+    /// The same width with every bit flipped
+    pub fn complement(&self) -> Self {
+        let mut bits = Self::new(self.len);
+        for i in 0..self.len {
+            bits.set(i, !self.get(i));
+        }
+        bits
+    }
+
+    /// The number written in binary
+    pub fn value(&self) -> BigUint {
+        (0..self.len).fold(BigUint::default(), |n, i| {
+            (n << 1u8) + u8::from(self.get(i))
+        })
+    }
+}
+
+impl FromStr for Bits {
+    type Err = Error;
+
+    /// Parses a line of `0`s and `1`s, errors are reported on line 1
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bits = Self::new(s.chars().count());
+        for (i, c) in s.chars().enumerate() {
+            match c {
+                '0' => {}
+                '1' => bits.set(i, true),
+                _ => {
+                    return Err(Error::parse(
+                        Day3::DAY,
+                        1,
+                        i + 1,
+                        format!("invalid bit {:?}", c),
+                    ))
+                }
+            }
+        }
+        Ok(bits)
+    }
+}
+
+/// The diagnostic report.
+/// `num_bits` is the width of the diagnostics, they all have the same.
+/// The diagnostics are sorted, the ones starting with the same bits are next to each other
+#[derive(Clone, Debug)]
+pub struct Report {
+    num_bits: usize,
+    diagnostics: Vec<Bits>,
+}
+
+impl Report {
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    pub fn diagnostics(&self) -> &[Bits] {
+        &self.diagnostics
+    }
+
+    /// Number of diagnostics with bit `i` set, for each bit
+    fn ones(&self) -> Vec<usize> {
+        let mut ones = vec![0; self.num_bits];
+        for diagnostic in &self.diagnostics {
+            for (i, count) in ones.iter_mut().enumerate() {
+                *count += usize::from(diagnostic.get(i));
+            }
+        }
+        ones
+    }
+
+    /// Keeps the diagnostics matching the criteria, one bit at a time, until one is left.
+    /// `None` if there are none left, the criteria kept a bit no diagnostic has.
+    ///
+    /// The diagnostics left share their first bits, so they are a range of the sorted
+    /// diagnostics, and the ones with the next bit set are the end of the range:
+    /// each bit is a binary search
+    pub fn rating(&self, criteria: &impl BitCriteria) -> Option<&Bits> {
+        let mut left = &self.diagnostics[..];
+        for i in 0..self.num_bits {
+            if left.len() <= 1 {
+                break;
+            }
+            let zeros = left.partition_point(|d| !d.get(i));
+            let (without, with) = left.split_at(zeros);
+            left = if criteria.keep(with.len(), without.len()) {
+                with
+            } else {
+                without
+            };
+        }
+        left.first()
+    }
+}
+
+/// Which diagnostics are kept at each bit while looking for a rating.
+/// Closures `|ones, zeros| -> bool` are criteria too
+pub trait BitCriteria {
+    /// The bit the diagnostics left must have, from the number of them with
+    /// this bit set and not set
+    fn keep(&self, ones: usize, zeros: usize) -> bool;
+}
+
+impl<F: Fn(usize, usize) -> bool> BitCriteria for F {
+    fn keep(&self, ones: usize, zeros: usize) -> bool {
+        self(ones, zeros)
+    }
+}
+
+/// The most common bit, `tie` if they are as common
+pub struct MostCommon {
+    pub tie: bool,
+}
+
+impl BitCriteria for MostCommon {
+    fn keep(&self, ones: usize, zeros: usize) -> bool {
+        if ones == zeros {
+            self.tie
+        } else {
+            ones > zeros
+        }
+    }
+}
+
+/// The least common bit, `tie` if they are as common
+pub struct LeastCommon {
+    pub tie: bool,
+}
+
+impl BitCriteria for LeastCommon {
+    fn keep(&self, ones: usize, zeros: usize) -> bool {
+        if ones == zeros {
+            self.tie
+        } else {
+            ones < zeros
+        }
+    }
+}
+
+/// Oxygen generator rating criteria
+pub const OXYGEN: MostCommon = MostCommon { tie: true };
+/// CO2 scrubber rating criteria
+pub const CO2_SCRUBBER: LeastCommon = LeastCommon { tie: false };
+
+pub struct Day3;
+
+/// Straightforward using a frequency vector where we count `1`
+/// Then we can calculate the most frequent bit if
+/// it appears more than half the number of lines
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut diagnostics: Vec<Bits> = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| line.trim().parse().map_err(|e: Error| e.at_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+        // 111100101100 -> 12 bits
+        let num_bits = diagnostics.first().map_or(0, Bits::len);
+        if let Some(i) = diagnostics.iter().position(|d| d.len() != num_bits) {
+            return Err(Error::parse(
+                Self::DAY,
+                i + 1,
+                1,
+                format!(
+                    "diagnostic has {} bits, the first one has {}",
+                    diagnostics[i].len(),
+                    num_bits
+                ),
+            ));
+        }
+        if num_bits == 0 && !diagnostics.is_empty() {
+            return Err(Error::parse(Self::DAY, 1, 1, "empty diagnostic"));
+        }
+        diagnostics.sort_unstable();
+        Ok(Report {
+            num_bits,
+            diagnostics,
+        })
+    }
+
+    fn part1(report: &Self::Input) -> Result<Answer, Error> {
+        let num_lines = report.diagnostics.len();
+        let mut gamma = Bits::new(report.num_bits);
+        for (i, &ones) in report.ones().iter().enumerate() {
+            gamma.set(i, ones > num_lines / 2);
+        }
+        let epsilon = gamma.complement();
+        let power = gamma.value() * epsilon.value();
+        debug!("{}", power); // 845186
+        Ok(Answer::from(BigInt::from(power)))
+    }
+
+    fn part2(report: &Self::Input) -> Result<Answer, Error> {
+        if report.diagnostics.is_empty() {
+            return Err(Error::invalid(Self::DAY, "the report is empty"));
+        }
+        let o2 = report
+            .rating(&OXYGEN)
+            .ok_or_else(|| Error::invalid(Self::DAY, "no diagnostic has the oxygen rating"))?;
+        let co2 = report
+            .rating(&CO2_SCRUBBER)
+            .ok_or_else(|| Error::invalid(Self::DAY, "no diagnostic has the CO2 rating"))?;
+        let life_support = o2.value() * co2.value();
+        debug!("{}", life_support); // 4636702
+        Ok(Answer::from(BigInt::from(life_support)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bits, Day3, LeastCommon, MostCommon, CO2_SCRUBBER, OXYGEN};
+    use crate::answer::Answer;
+    use crate::error::Error;
+    use crate::solution::Solution;
+    use num::BigInt;
+    #[test]
+    fn test_diagnostic_width() {
+        let report = Day3::parse("101\n011\n111\n").unwrap();
+        assert_eq!(report.num_bits(), 3);
+        // gamma 111, epsilon 000
+        assert_eq!(Day3::part1(&report).unwrap(), 0);
+        assert!(matches!(
+            Day3::parse("101\n0110\n").unwrap_err(),
+            Error::Parse { line: 2, .. }
+        ));
+        assert!(matches!(
+            Day3::parse("101\n012\n").unwrap_err(),
+            Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+
+        // Wider than a u64: 1 followed by 69 0s, and its complement
+        let high = format!("1{}", "0".repeat(69));
+        let low = format!("0{}", "1".repeat(69));
+        let bits: Bits = high.parse().unwrap();
+        assert_eq!(bits.len(), 70);
+        assert!(bits.get(0) && !bits.get(69));
+        assert_eq!(bits.complement(), low.parse().unwrap());
+        let report = Day3::parse(&format!("{}\n{}\n{}\n", high, high, low)).unwrap();
+        let gamma = BigInt::from(1) << 69u8;
+        let epsilon = gamma.clone() - 1;
+        assert_eq!(
+            Day3::part1(&report).unwrap(),
+            Answer::from(gamma * &epsilon)
+        );
+        // Oxygen keeps the two `high`, CO2 scrubber `low`
+        assert_eq!(
+            Day3::part2(&report).unwrap(),
+            Answer::from((BigInt::from(1) << 69u8) * epsilon)
+        );
+    }
+    #[test]
+    fn test_bit_criteria() {
+        let report = Day3::parse(
+            "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n",
+        )
+        .unwrap();
+        assert_eq!(report.rating(&OXYGEN).unwrap().value(), 23u8.into());
+        assert_eq!(report.rating(&CO2_SCRUBBER).unwrap().value(), 10u8.into());
+        // Some bits are as common in the example, the tie decides
+        assert_eq!(
+            report.rating(&MostCommon { tie: false }).unwrap().value(),
+            22u8.into()
+        );
+        assert_eq!(
+            report.rating(&LeastCommon { tie: true }).unwrap().value(),
+            15u8.into()
+        );
+        // Always the ones, or always the zeros
+        let max = report.rating(&|_, _| true).unwrap();
+        let min = report.rating(&|_, _| false).unwrap();
+        assert_eq!((max.value(), min.value()), (30u8.into(), 2u8.into()));
+
+        // Keeping a bit none of them has leaves no rating
+        let report = Day3::parse("10\n10\n").unwrap();
+        assert_eq!(report.rating(&CO2_SCRUBBER), None);
+        assert!(Day3::part2(&report).is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::answer::Answer;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use itertools::Itertools;
+use tracing::debug;
+
+/// A board data structure that keeps
+/// `numbers` =  numbers of the board
+/// `marked` = matrix of bools to see if a number has been marked
+/// `last_number` the last number played to calculate the score
+#[derive(Clone, Debug)]
+pub struct Board {
+    numbers: [[isize; 5]; 5],
+    marked: [[bool; 5]; 5],
+    last_number: Option<isize>,
+}
+impl Board {
+    pub fn new(numbers: [[isize; 5]; 5]) -> Self {
+        Self {
+            numbers,
+            marked: [[false; 5]; 5],
+            last_number: None,
+        }
+    }
+
+    /// Calculate the score of the board as the sum of unmarked numbers
+    /// multiplied with the last number played.
+    /// If no number has been played returns None
+    pub fn score(&self) -> Option<isize> {
+        if let Some(last_number) = self.last_number {
+            let mut score = 0;
+            for (row_numbers, row_marked) in self.numbers.iter().zip(self.marked) {
+                for (elem, mark) in row_numbers.iter().zip(row_marked) {
+                    // Add unmarked
+                    if !mark {
+                        score += elem;
+                    }
+                }
+            }
+            return Some(score * last_number);
+        }
+        None
+    }
+
+    /// Checks winning conditions
+    pub fn check_win(&self) -> bool {
+        // Check rows
+        if self.marked.iter().any(|&row| row.iter().all(|&mark| mark)) {
+            return true;
+        }
+        // Check columns
+        if (0..5).any(|j| (0..5).all(|i| self.marked[i][j])) {
+            return true;
+        }
+        false
+    }
+    /// Marks the first occurence of a given number.
+    /// Iterates through rows then columns.
+    /// Returns false if the number is not found
+    pub fn mark_number(&mut self, number: isize) -> bool {
+        for (row_numbers, row_marked) in self.numbers.iter_mut().zip(self.marked.iter_mut()) {
+            for (&mut elem, mark) in row_numbers.iter_mut().zip(row_marked.iter_mut()) {
+                if elem == number {
+                    *mark = true;
+                    self.last_number = Some(number);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Plays the numbers on copies of the boards.
+/// Returns the boards and the order in which they won
+fn play(winning_numbers: &[isize], boards: &[Board]) -> (Vec<Board>, Vec<usize>) {
+    let mut boards = boards.to_vec();
+    // Vector of bools to keep track of the winning boards
+    let mut winning_boards = vec![false; boards.len()];
+    let mut ranking: Vec<usize> = Vec::new();
+    'outer: for &number in winning_numbers {
+        for (i, board) in boards.iter_mut().enumerate() {
+            // Only boards that haven't won play
+            if !winning_boards[i] {
+                board.mark_number(number);
+                if board.check_win() {
+                    winning_boards[i] = true;
+                    ranking.push(i)
+                }
+            }
+            // stop if all boards won
+            if winning_boards.iter().all(|&e| e) {
+                break 'outer;
+            }
+        }
+    }
+    (boards, ranking)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Giant Squid";
+    /// The winning numbers and the boards
+    type Input = (Vec<isize>, Vec<Board>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        // Read winning numbers
+        let first_line = input
+            .lines()
+            .next()
+            .ok_or_else(|| Error::parse(Self::DAY, 1, 1, "empty input"))?;
+        let winning_numbers: Vec<isize> = first_line
+            .trim()
+            .split(',')
+            .map(|s| {
+                s.parse::<isize>().map_err(|e| {
+                    Error::parse(
+                        Self::DAY,
+                        1,
+                        column(first_line, s),
+                        format!("invalid number {:?}: {}", s, e),
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut boards: Vec<Board> = Vec::new();
+        // Read 6 lines at a time to account for the empty line
+        for (k, lines) in input.lines().skip(1).chunks(6).into_iter().enumerate() {
+            // Line number of the first row of the board
+            let first_row = 3 + 6 * k;
+            let mut numbers = [[0; 5]; 5];
+            let mut rows = 0;
+            // Skip the empty line
+            for (i, line) in lines.skip(1).enumerate() {
+                let line_numbers: Vec<isize> = line
+                    .split_whitespace()
+                    .map(|s| {
+                        s.parse::<isize>().map_err(|e| {
+                            Error::parse(
+                                Self::DAY,
+                                first_row + i,
+                                column(line, s),
+                                format!("invalid number {:?}: {}", s, e),
+                            )
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+                numbers[i] = line_numbers.try_into().map_err(|v: Vec<isize>| {
+                    Error::parse(
+                        Self::DAY,
+                        first_row + i,
+                        1,
+                        format!("expected 5 numbers on a board row, found {}", v.len()),
+                    )
+                })?;
+                rows += 1;
+            }
+            if rows != 5 {
+                return Err(Error::parse(
+                    Self::DAY,
+                    first_row + rows,
+                    1,
+                    format!("expected 5 rows on a board, found {}", rows),
+                ));
+            }
+            boards.push(Board::new(numbers));
+        }
+        Ok((winning_numbers, boards))
+    }
+
+    /// Score of the first board to win
+    fn part1((winning_numbers, boards): &Self::Input) -> Result<Answer, Error> {
+        let (boards, ranking) = play(winning_numbers, boards);
+        let first = *ranking
+            .first()
+            .ok_or_else(|| Error::invalid(Self::DAY, "no board wins"))?;
+        // A board that won had a number played so it has a score
+        let score = boards[first].score().unwrap_or(0);
+        debug!("First board is {} with a score of {}", first + 1, score);
+        Ok(Answer::from(score))
+    }
+
+    /// Score of the last board to win
+    fn part2((winning_numbers, boards): &Self::Input) -> Result<Answer, Error> {
+        let (boards, ranking) = play(winning_numbers, boards);
+        let last = *ranking
+            .last()
+            .ok_or_else(|| Error::invalid(Self::DAY, "no board wins"))?;
+        let score = boards[last].score().unwrap_or(0);
+        debug!("Last board is {} with a score of {}", last + 1, score);
+        Ok(Answer::from(score))
+    }
+}
\ No newline at end of file
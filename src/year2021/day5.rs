@@ -1,10 +1,13 @@
 use std::{collections::HashMap, ops::Add};
 
-use crate::utils::read_challenge_data;
+use crate::answer::Answer;
+use crate::error::{column, Error};
+use crate::solution::Solution;
 use nom::{
     bytes::complete::tag, character::complete::digit1, combinator::map_res,
     sequence::separated_pair, IResult,
 };
+use tracing::debug;
 
 /// Parser for the input
 /// I could've used regex but I wanted to take a look at `nom`
@@ -48,8 +51,8 @@ fn get_direction(p: &Point, q: &Point) -> Point {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point {
-    x: isize,
-    y: isize,
+    pub x: isize,
+    pub y: isize,
 }
 
 impl Add for Point {
@@ -69,16 +72,49 @@ pub struct Line {
     direction: Point,
 }
 impl Line {
-    /// Parse the arrow notation and create a line from it
-    fn from_arrow(s: &str) -> Self {
-        let (_, ((x0, y0), (x1, y1))) = parser(s).unwrap();
+    /// Parse the arrow notation and create a line from it.
+    /// `line` is the line number in the input, used for errors
+    pub fn from_arrow(line: usize, s: &str) -> Result<Self, Error> {
+        let (rest, ((x0, y0), (x1, y1))) = parser(s).map_err(|e| {
+            let rest = match &e {
+                nom::Err::Error(e) | nom::Err::Failure(e) => e.input,
+                nom::Err::Incomplete(_) => "",
+            };
+            Error::parse(
+                Day5::DAY,
+                line,
+                column(s, rest),
+                "expected `x1,y1 -> x2,y2`",
+            )
+        })?;
+        if !rest.trim().is_empty() {
+            return Err(Error::parse(
+                Day5::DAY,
+                line,
+                column(s, rest),
+                format!("unexpected {:?} after the line", rest),
+            ));
+        }
+        // Only horizontal, vertical and 45 degrees lines can be walked with `direction`
+        if x0 != x1 && y0 != y1 && (x1 - x0).abs() != (y1 - y0).abs() {
+            return Err(Error::parse(
+                Day5::DAY,
+                line,
+                1,
+                "the line is not horizontal, vertical or diagonal",
+            ));
+        }
         let p = Point { x: x0, y: y0 };
         let q = Point { x: x1, y: y1 };
         let direction = get_direction(&p, &q);
-        Self { p, q, direction }
+        Ok(Self { p, q, direction })
+    }
+    /// The start and the end of the line, in the order of the input
+    pub fn endpoints(&self) -> (Point, Point) {
+        (self.p, self.q)
     }
     /// Returns all points from the line
-    fn all_points(&self) -> Vec<Point> {
+    pub fn all_points(&self) -> Vec<Point> {
         let mut t = self.p;
         let mut points = Vec::new();
         while t != self.q {
@@ -90,15 +126,9 @@ impl Line {
     }
 }
 
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(5, run_example);
-    let mut lines: Vec<Line> = Vec::new();
-
-    //println!("{:?}", parser("1,2 -> 1,10"));
-    for line in input.lines() {
-        lines.push(Line::from_arrow(line));
-    }
-
+/// Counts the points where at least 2 lines overlap.
+/// If `diagonals` is false the diagonal lines are skipped
+fn count_overlaps(lines: &[Line], diagonals: bool) -> usize {
     let mut point_freq_dict: HashMap<Point, u32> = HashMap::new();
 
     let horizontal_vertical = [
@@ -110,7 +140,7 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
 
     for line in lines {
         // If we are doing part 1 skip the points that are on diagonals
-        if part1 && !horizontal_vertical.contains(&line.direction) {
+        if !diagonals && !horizontal_vertical.contains(&line.direction) {
             continue;
         }
         for point in line.all_points() {
@@ -120,22 +150,31 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
     }
     //println!("{:?}", point_freq_dict);
     let res = point_freq_dict.into_values().filter(|&e| e > 1).count();
-    println!("{}", res); // 8622 // 22037
-    res as usize
+    debug!("{}", res); // 8622 // 22037
+    res
 }
 
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    #[test]
-    fn test_example() {
-        assert_eq!(solve(true, true), 5);
-        assert_eq!(solve(true, false), 12);
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        //println!("{:?}", parser("1,2 -> 1,10"));
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Line::from_arrow(i + 1, line))
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(count_overlaps(lines, false)))
     }
-    #[test]
 
-    fn test_problem() {
-        assert_eq!(solve(false, true), 8622);
-        assert_eq!(solve(false, false), 22037);
+    fn part2(lines: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(count_overlaps(lines, true)))
     }
 }
\ No newline at end of file
@@ -1,15 +1,10 @@
-use crate::utils::read_challenge_data;
-
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(6, run_example);
-    let fishes: Vec<usize> = input
-        .trim()
-        .split(',')
-        .filter_map(|s| s.parse().ok())
-        .collect();
-
-    let days = if part1 { 80 } else { 256 };
+use crate::answer::Answer;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use tracing::debug;
 
+/// Number of fishes after `days` days
+fn simulate(fishes: &[usize], days: usize) -> usize {
     // Naive solution, doesn't work for part 2
     // let mut fishes = fishes;
     // for day in 0..days {
@@ -38,7 +33,7 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
     let mut weekdays: Vec<(u64, u64)> = vec![(0, 0); 7];
 
     // Initial setup.
-    for fish in fishes {
+    for &fish in fishes {
         weekdays[fish].0 += 1;
     }
     for day in 0..days {
@@ -50,22 +45,44 @@ pub fn solve(run_example: bool, part1: bool) -> usize {
         weekdays[day % 7].1 = 0;
     }
     let res = weekdays.iter().fold(0, |acc, e| acc + e.0 + e.1);
-    println!("{}", res); // 360761 // 1632779838045
+    debug!("{}", res); // 360761 // 1632779838045
     res as usize
 }
 
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    #[test]
-    fn test_example() {
-        assert_eq!(solve(true, true), 5934);
-        assert_eq!(solve(true, false), 26984457539);
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Lanternfish";
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        line.split(',')
+            .map(|s| match s.trim().parse::<usize>() {
+                // The weekdays only keep fishes with a timer up to 6
+                Ok(fish) if fish <= 6 => Ok(fish),
+                Ok(fish) => Err(Error::parse(
+                    Self::DAY,
+                    1,
+                    column(line, s),
+                    format!("timer {} is bigger than 6", fish),
+                )),
+                Err(e) => Err(Error::parse(
+                    Self::DAY,
+                    1,
+                    column(line, s),
+                    format!("invalid timer {:?}: {}", s, e),
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(fishes: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(simulate(fishes, 80)))
     }
-    #[test]
 
-    fn test_problem() {
-        assert_eq!(solve(false, true), 360761);
-        assert_eq!(solve(false, false), 1632779838045);
+    fn part2(fishes: &Self::Input) -> Result<Answer, Error> {
+        Ok(Answer::from(simulate(fishes, 256)))
     }
 }
\ No newline at end of file
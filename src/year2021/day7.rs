@@ -0,0 +1,70 @@
+use crate::answer::Answer;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use tracing::debug;
+
+/// Minimum fuel needed to align the crabs.
+/// `constant_rate` is true for part 1, where each step costs 1
+fn min_fuel(crab_pos: &[isize], constant_rate: bool) -> Result<usize, Error> {
+    //Straightforward solution:
+    // 1. Find posible positions
+    // 2. For each position `p` calculate the distances to the position and sum them
+    // 3. Find the minimum
+    // For part 2 instead of returning the distance return the gauss sum
+    // (1 + 2 + 3 + ... + n) = n * (n + 1) / 2
+    let (min, max) = match (crab_pos.iter().min(), crab_pos.iter().max()) {
+        (Some(&min), Some(&max)) => (min, max),
+        _ => return Err(Error::invalid(Day7::DAY, "there are no crabs")),
+    };
+    let distances: Vec<isize> = (min..max + 1)
+        .map(|p| {
+            crab_pos
+                .iter()
+                .map(|&pos| {
+                    let dist = (pos - p).abs();
+                    if constant_rate {
+                        dist
+                    } else {
+                        dist * (dist + 1) / 2
+                    }
+                })
+                .sum()
+        })
+        .collect();
+    // There is at least one position between min and max
+    let m = distances.into_iter().min().unwrap_or(0);
+    debug!("Minimum distance {}", m);
+    Ok(m as usize)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+    type Input = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let line = input.trim();
+        line.split(',')
+            .map(|s| {
+                s.trim().parse().map_err(|e| {
+                    Error::parse(
+                        Self::DAY,
+                        1,
+                        column(line, s),
+                        format!("invalid position {:?}: {}", s, e),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn part1(crab_pos: &Self::Input) -> Result<Answer, Error> {
+        min_fuel(crab_pos, true).map(Answer::from)
+    }
+
+    fn part2(crab_pos: &Self::Input) -> Result<Answer, Error> {
+        min_fuel(crab_pos, false).map(Answer::from)
+    }
+}
\ No newline at end of file
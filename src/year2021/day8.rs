@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::{column, Error};
+use crate::solution::Solution;
+use tracing::debug;
+
+/// Reverse lookup in a Hashmap<char, char>
+fn reverse_lookup(map: &HashMap<char, char>, value: char) -> Option<char> {
+    for (&k, &v) in map.iter() {
+        if v == value {
+            return Some(k);
+        }
+    }
+    None
+}
+
+/// 1. Find unique letters by counts
+///    `a` -- 8 times
+///    `b` -- 6 times -- can find
+///    `c` -- 8 times
+///    `d` -- 7 times
+///    `e` -- 4 times -- can find
+///    `f` -- 9 times -- can find
+///    `g` -- 7 times
+/// 2. Intersection
+/// 3. 1 intersected with 7 gives `a`
+/// 4. One we know `f` we can find `c` from 1.
+/// 5. Once you know `c` you can get 'd' from 4;
+/// 6. Once you know 'd' the last one to find is 'g' which we can find from 8
+///
+/// Returns None if the digits are not consistent
+fn create_map(digits: Vec<&str>) -> Option<HashMap<char, char>> {
+    let mut map: HashMap<char, char> = HashMap::new();
+
+    // Count digit frequency
+    let mut count_map: HashMap<char, i32> = HashMap::new();
+    let mut seven = "";
+    let mut one = "";
+    let mut four = "";
+    let mut eight = "";
+    for digit in digits {
+        for c in digit.chars() {
+            count_map.insert(c, count_map.get(&c).unwrap_or(&0) + 1);
+        }
+        // find seven and one
+        match digit.len() {
+            2 => one = digit,
+            3 => seven = digit,
+            4 => four = digit,
+            7 => eight = digit,
+            _ => (),
+        };
+    }
+    for (key, value) in count_map {
+        match value {
+            4 => map.insert(key, 'e'),
+            6 => map.insert(key, 'b'),
+            9 => map.insert(key, 'f'),
+            _ => None,
+        };
+    }
+    // Find `a`
+    for c in seven.chars() {
+        if !one.contains(c) {
+            map.insert(c, 'a');
+            break;
+        }
+    }
+    // Find `c`
+    let f = reverse_lookup(&map, 'f')?;
+    for c in one.chars() {
+        if c != f {
+            map.insert(c, 'c');
+            break;
+        }
+    }
+    // Find 'd'
+    let bcf = [
+        reverse_lookup(&map, 'b')?,
+        reverse_lookup(&map, 'c')?,
+        reverse_lookup(&map, 'f')?,
+    ];
+    for c in four.chars() {
+        if !bcf.contains(&c) {
+            map.insert(c, 'd');
+            break;
+        }
+    }
+    // Find `g`
+
+    for c in eight.chars() {
+        if !map.keys().copied().any(|x| x == c) {
+            map.insert(c, 'g');
+            break;
+        }
+    }
+    Some(map)
+}
+
+/// Sort the signal alphabetically and decode it
+fn decode_signal_map(signal: &str, map: &HashMap<char, char>) -> Option<isize> {
+    let s_decoded: String = signal
+        .chars()
+        .map(|c| map.get(&c))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .sorted()
+        .collect();
+    match s_decoded.as_str() {
+        "abcefg" => Some(0),
+        "cf" => Some(1),
+        "acdeg" => Some(2),
+        "acdfg" => Some(3),
+        "bcdf" => Some(4),
+        "abdfg" => Some(5),
+        "abdefg" => Some(6),
+        "acf" => Some(7),
+        "abcdefg" => Some(8),
+        "abcdfg" => Some(9),
+        _ => None,
+    }
+}
+
+/// Decode unique signals based on the length of the signal
+fn decode_signal(signal: &str) -> Option<isize> {
+    match signal.len() {
+        2 => Some(1),
+        3 => Some(7),
+        4 => Some(4),
+        7 => Some(8),
+        _ => None,
+    }
+}
+/// The 10 unique signal patterns and the 4 digit output value
+pub struct Entry {
+    patterns: Vec<String>,
+    output: Vec<String>,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut entries = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let s: Vec<&str> = line.split(" | ").collect();
+            if s.len() != 2 {
+                return Err(Error::parse(
+                    Self::DAY,
+                    i + 1,
+                    1,
+                    "expected `<patterns> | <output>`",
+                ));
+            }
+            // Signals are made of the segments `a` to `g`
+            for signal in s[0].split(' ').chain(s[1].split(' ')) {
+                if signal.is_empty() || !signal.chars().all(|c| ('a'..='g').contains(&c)) {
+                    return Err(Error::parse(
+                        Self::DAY,
+                        i + 1,
+                        column(line, signal),
+                        format!("invalid signal {:?}", signal),
+                    ));
+                }
+            }
+            let patterns: Vec<String> = s[0].split(' ').map(|d| d.to_string()).collect();
+            let output: Vec<String> = s[1].split(' ').map(|d| d.to_string()).collect();
+            if patterns.len() != 10 || output.len() != 4 {
+                return Err(Error::parse(
+                    Self::DAY,
+                    i + 1,
+                    1,
+                    format!(
+                        "expected 10 patterns and 4 output digits, found {} and {}",
+                        patterns.len(),
+                        output.len()
+                    ),
+                ));
+            }
+            // println!("{:?}", patterns);
+            // println!("{:?}", output);
+            entries.push(Entry { patterns, output });
+        }
+        Ok(entries)
+    }
+
+    fn part1(entries: &Self::Input) -> Result<Answer, Error> {
+        let mut c = 0;
+        for entry in entries {
+            for s in &entry.output {
+                if decode_signal(s).is_some() {
+                    c += 1;
+                }
+            }
+        }
+        debug!("{}", c); // 239
+        Ok(Answer::from(c))
+    }
+
+    fn part2(entries: &Self::Input) -> Result<Answer, Error> {
+        let mut c = 0;
+        for (i, entry) in entries.iter().enumerate() {
+            let undecodable =
+                || Error::invalid(Self::DAY, format!("couldn't decode the entry {}", i + 1));
+            // Create map from the first 10 digits given
+            let map = create_map(entry.patterns.iter().map(|d| d.as_str()).collect())
+                .ok_or_else(undecodable)?;
+            //println!("{:?}", map);
+            // Decode last digits and construct the number
+            let mut n = 0;
+            for (i, digit) in entry.output.iter().rev().enumerate() {
+                n += 10isize.pow(i as u32)
+                    * decode_signal_map(digit, &map).ok_or_else(undecodable)?;
+            }
+            c += n;
+        }
+        debug!("{}", c); // 946346
+        Ok(Answer::from(c))
+    }
+}
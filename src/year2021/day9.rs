@@ -1,8 +1,12 @@
 use std::collections::HashSet;
 
-use crate::utils::read_challenge_data;
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::utils::{Grid, Position};
+use tracing::debug;
 
-type Point = (usize, usize);
+type Point = Position;
 
 /// Struct that represents the Basin.
 /// `low point` -- we keep the local minimum
@@ -19,70 +23,42 @@ struct Basin {
 /// `heightmap` -- given matrix of heights
 /// `basins` -- A vector of basins in the cave.
 ///     Starts empty and fills after calling `find_basins`
-/// `rows, cols` number of rows and cols of the heightmap
 struct Cave {
-    heightmap: Vec<Vec<isize>>,
+    heightmap: Grid<u32>,
     basins: Vec<Basin>,
-    rows: usize,
-    cols: usize,
 }
 
 impl Cave {
     /// Creates a new Cave from the heightmap
-    fn new(heightmap: Vec<Vec<isize>>) -> Self {
-        let rows = heightmap.len();
-        let cols = heightmap[0].len();
+    fn new(heightmap: Grid<u32>) -> Self {
         let basins = Vec::new();
-        Self {
-            heightmap,
-            basins,
-            rows,
-            cols,
-        }
+        Self { heightmap, basins }
     }
 
-    /// Check if it's a local minimum. Also takes care of boundaries
+    /// Check if it's a local minimum. The grid takes care of boundaries
     fn is_local_minimum(&self, pos: Point) -> bool {
-        let (i, j) = pos;
-        let elem = self.heightmap[i][j];
-        if i >= 1 && elem >= self.heightmap[i - 1][j] {
-            return false;
-        }
-        if i < self.rows - 1 && elem >= self.heightmap[i + 1][j] {
-            return false;
-        }
-        if j >= 1 && elem >= self.heightmap[i][j - 1] {
-            return false;
-        }
-        if j < self.cols - 1 && elem >= self.heightmap[i][j + 1] {
-            return false;
-        }
-        true
+        let elem = self.heightmap[pos];
+        self.heightmap
+            .neighbours4(pos)
+            .all(|n| elem < self.heightmap[n])
     }
 
     /// Naive way to get all low points
     fn low_points(&self) -> Vec<Point> {
-        let mut points = Vec::new();
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                if self.is_local_minimum((i, j)) {
-                    points.push((i, j));
-                }
-            }
-        }
-        points
+        self.heightmap
+            .positions()
+            .filter(|&pos| self.is_local_minimum(pos))
+            .collect()
     }
 
     /// Try to roll for each point.
     fn find_basins(&mut self) {
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                // Skip `9`
-                if self.heightmap[i][j] == 9 {
-                    continue;
-                }
-                self.roll((i, j));
+        for pos in self.heightmap.positions() {
+            // Skip `9`
+            if self.heightmap[pos] == 9 {
+                continue;
             }
+            self.roll(pos);
         }
     }
 
@@ -133,71 +109,53 @@ impl Cave {
 
     /// Try to get the next point. Returns None if it's a local minimum
     fn next_point(&self, current_point: Point) -> Option<Point> {
-        let (i, j) = current_point;
-        let mut elem = self.heightmap[i][j];
+        let mut elem = self.heightmap[current_point];
         let mut next_point = None;
-        if i >= 1 && elem > self.heightmap[i - 1][j] {
-            next_point = Some((i - 1, j));
-            elem = self.heightmap[i - 1][j];
-        }
-        if i < self.rows - 1 && elem > self.heightmap[i + 1][j] {
-            next_point = Some((i + 1, j));
-            elem = self.heightmap[i + 1][j];
-        }
-        if j >= 1 && elem > self.heightmap[i][j - 1] {
-            next_point = Some((i, j - 1));
-            elem = self.heightmap[i][j - 1];
-        }
-        if j < self.cols - 1 && elem > self.heightmap[i][j + 1] {
-            next_point = Some((i, j + 1));
+        for n in self.heightmap.neighbours4(current_point) {
+            if elem > self.heightmap[n] {
+                next_point = Some(n);
+                elem = self.heightmap[n];
+            }
         }
         next_point
     }
 }
-pub fn solve(run_example: bool, part1: bool) -> usize {
-    let input = read_challenge_data(9, run_example);
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+    /// The heightmap
+    type Input = Grid<u32>;
 
-    let mut heightmap: Vec<Vec<isize>> = vec![];
-    for line in input.lines() {
-        let l: Vec<isize> = line
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as isize)
-            .collect();
-        heightmap.push(l);
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(Self::DAY, input, |c| c.to_digit(10))
     }
-    let mut cave = Cave::new(heightmap);
 
-    let res = if part1 {
+    fn part1(heightmap: &Self::Input) -> Result<Answer, Error> {
+        let cave = Cave::new(heightmap.clone());
         // Get the low points coords and sum them up
         let points = cave.low_points();
-        points.iter().map(|(i, j)| 1 + cave.heightmap[*i][*j]).sum()
-    } else {
+        let res: u32 = points.iter().map(|&p| 1 + cave.heightmap[p]).sum();
+        debug!("{}", res);
+        Ok(Answer::from(res))
+    }
+
+    fn part2(heightmap: &Self::Input) -> Result<Answer, Error> {
+        let mut cave = Cave::new(heightmap.clone());
         // Find the basins
         cave.find_basins();
         // Sort the basins by their length
         cave.basins
-            .sort_by(|a, b| b.points.len().cmp(&a.points.len()));
+            .sort_by_key(|basin| std::cmp::Reverse(basin.points.len()));
+        if cave.basins.len() < 3 {
+            return Err(Error::invalid(Self::DAY, "there are less than 3 basins"));
+        }
         // Take the top 3 and multiply them
-        cave.basins[..3]
+        let res = cave.basins[..3]
             .iter()
-            .fold(1, |acc, basin| acc * basin.points.len()) as isize
-    };
-    println!("{}", res);
-    res as usize
-}
-
-#[cfg(test)]
-mod tests {
-    use super::solve;
-    #[test]
-    fn test_example() {
-        assert_eq!(solve(true, true), 15);
-        assert_eq!(solve(true, false), 1134);
-    }
-    #[test]
-
-    fn test_problem() {
-        assert_eq!(solve(false, true), 444);
-        assert_eq!(solve(false, false), 1168440);
+            .fold(1, |acc, basin| acc * basin.points.len());
+        debug!("{}", res);
+        Ok(Answer::from(res))
     }
 }
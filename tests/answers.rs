@@ -0,0 +1,40 @@
+//! Regression tests for the known answers in `answers.toml`.
+//! `build.rs` generates one `answer_test!` for each entry of the file
+use std::{io::ErrorKind, path::Path};
+
+use advent_of_rust::{
+    answers::{self, InputKind},
+    solution, utils, Error,
+};
+
+/// Solves the part and compares it to the answer in the file.
+/// Passes without solving anything if the input file is missing
+fn check(year: u32, day: u32, part: u32, input: InputKind) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(answers::ANSWERS_FILE);
+    let entries = answers::load(&path).unwrap();
+    let expected = answers::find(&entries, year, day, part, input).unwrap();
+
+    let data = match utils::read_challenge_data(year, day, input == InputKind::Example) {
+        Ok(data) => data,
+        Err(Error::Io { path, source }) if source.kind() == ErrorKind::NotFound => {
+            eprintln!("skipped, {} is missing", path.display());
+            return;
+        }
+        Err(e) => panic!("{}", e),
+    };
+    let answer = solution::find(year, day, part)
+        .and_then(|challenge| challenge.run(&data, part == 1))
+        .unwrap_or_else(|e| panic!("{}", e));
+    assert_eq!(&answer, expected);
+}
+
+macro_rules! answer_test {
+    ($name:ident, $year:expr, $day:expr, $part:expr, $input:ident) => {
+        #[test]
+        fn $name() {
+            check($year, $day, $part, InputKind::$input);
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
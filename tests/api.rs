@@ -0,0 +1,49 @@
+use advent_of_rust::{
+    year2021::{
+        day1::Day1,
+        day12::{CaveType, Day12, UndirectedGraph},
+        day16::Day16,
+        day4::Board,
+        day5::Line,
+    },
+    Solution,
+};
+
+#[test]
+fn test_parse_and_solve() {
+    let depths = Day1::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n").unwrap();
+    assert_eq!(Day1::part1(&depths).unwrap(), 7);
+    assert_eq!(Day1::part2(&depths).unwrap(), 5);
+
+    let packet = Day16::parse("8A004A801A8002F478").unwrap();
+    assert_eq!(packet.version_sum(), 16);
+    assert_eq!(
+        Day16::part2(&Day16::parse("9C0141080250320F1802104A08").unwrap()).unwrap(),
+        1
+    );
+
+    let caves = Day12::parse("start-A\nA-end\n").unwrap();
+    assert_eq!(Day12::part1(&caves).unwrap(), 1);
+}
+
+#[test]
+fn test_types() {
+    let mut g = UndirectedGraph::new();
+    g.add_node("a", CaveType::Small);
+    g.add_node("B", CaveType::Big);
+    assert!(g.add_edge("a", "B").is_ok());
+    assert!(g.add_edge("a", "c").is_err());
+    assert!(g.neighbours("B").unwrap().contains("a"));
+
+    let mut numbers = [[0; 5]; 5];
+    numbers[2] = [1, 2, 3, 4, 5];
+    let mut board = Board::new(numbers);
+    for n in 1..=5 {
+        assert!(board.mark_number(n));
+    }
+    assert!(board.check_win());
+    assert_eq!(board.score(), Some(0));
+
+    let line = Line::from_arrow(1, "0,0 -> 2,2").unwrap();
+    assert_eq!(line.all_points().len(), 3);
+}
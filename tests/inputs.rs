@@ -0,0 +1,72 @@
+//! The input fetcher with the real HTTP client against a local stub server.
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    thread::{self, JoinHandle},
+};
+
+use advent_of_rust::{
+    inputs::{Fetcher, UreqClient},
+    Error,
+};
+
+/// Answers one connection for each response, then stops.
+/// Returns the base URL and a handle giving back the request heads
+fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head += &line;
+            }
+            requests.push(head);
+            write!(
+                stream,
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+        requests
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_fetch_and_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let (url, server) = serve(vec![(200, "199\n200\n208\n"), (404, "Not Found")]);
+    let fetcher = Fetcher::new(UreqClient, dir.path())
+        .base_url(url)
+        .account("me")
+        .session("secret");
+
+    assert_eq!(fetcher.input(2021, 1).unwrap(), "199\n200\n208\n");
+    assert!(matches!(fetcher.input(2021, 2), Err(Error::Fetch { .. })));
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("GET /2021/day/1/input "));
+    assert!(requests[0].contains("session=secret"));
+    assert!(!fetcher.is_cached(2021, 2));
+
+    // The server is gone, the cached input is still there
+    assert_eq!(fetcher.input(2021, 1).unwrap(), "199\n200\n208\n");
+    assert_eq!(
+        fs::read_to_string(dir.path().join("me/2021/day1.txt")).unwrap(),
+        "199\n200\n208\n"
+    );
+    assert!(matches!(fetcher.input(2021, 3), Err(Error::Fetch { .. })));
+}
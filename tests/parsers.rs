@@ -0,0 +1,234 @@
+//! Properties of the input parsers: any text is either parsed or rejected with an error,
+//! they never panic. The same check runs as `cargo fuzz` targets in `fuzz/`.
+use advent_of_rust::{
+    year2021::{
+        day1::Day1,
+        day16::Day16,
+        day17::Day17,
+        day2::{Command, Day2},
+        day21::Day21,
+        day22::Day22,
+        day5::{Day5, Point},
+        day6::Day6,
+        day7::Day7,
+    },
+    Challenge, Solution, YEARS,
+};
+use proptest::prelude::*;
+
+fn challenges() -> impl Iterator<Item = &'static dyn Challenge> {
+    YEARS
+        .iter()
+        .flat_map(|&(_, solutions)| solutions.iter().copied())
+}
+
+/// Inputs that made a parser, or the solver after it, panic, minimized.
+/// Each one must now be an error.
+/// Add the output of `cargo fuzz tmin` here when a fuzz target finds a crash
+const REGRESSIONS: [(u32, &str); 11] = [
+    // The `digit1` pairs were unwrapped
+    (5, "1,2 -> 3"),
+    // `x=<a>..<b>` without the `, y=` half
+    (17, "target area: x=1"),
+    // `[1..]` on an empty line
+    (21, "\n"),
+    // `ss[2..]` on a range shorter than `x=`
+    (22, "on x"),
+    // Not a hex digit
+    (16, "G"),
+    // No algorithm line
+    (20, ""),
+    // No drawn numbers
+    (4, ""),
+    // A fold without `=`
+    (13, "fold along x"),
+    // No ` | ` separator
+    (8, "a"),
+    // The solver took `abs` of the lowest `i64`
+    (17, "target area: x=0..1, y=-9223372036854775808..-1"),
+    // The solver went one past the highest `isize`
+    (22, "on x=0..9223372036854775807,y=0..0,z=0..0"),
+];
+
+#[test]
+fn test_regressions() {
+    for (day, input) in REGRESSIONS {
+        let challenge = challenges().find(|c| c.day() == day).unwrap();
+        assert!(
+            challenge.check_input(input).is_err(),
+            "day {} accepted {:?}",
+            day,
+            input
+        );
+    }
+}
+
+/// `depth` sum packets, each holding the next one, around a literal 1
+fn nested_packet(depth: usize) -> String {
+    // Version 0, sum, counted by number with 1 subpacket
+    let mut bits = "000000100000000001".repeat(depth);
+    // Version 0, literal, last group with value 1
+    bits.push_str("00010000001");
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    bits.as_bytes()
+        .chunks(4)
+        .map(|digit| {
+            let digit = std::str::from_utf8(digit).unwrap();
+            format!("{:X}", u8::from_str_radix(digit, 2).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_nested_packets() {
+    let packet = Day16::parse(&nested_packet(50)).unwrap();
+    assert_eq!(Day16::part2(&packet).unwrap(), 1);
+    // Read on the 2MB stack of a test thread
+    assert!(Day16::parse(&nested_packet(100_000)).is_err());
+}
+
+/// The endpoints of a horizontal, vertical or diagonal line, going either way
+fn line() -> impl Strategy<Value = (Point, Point)> {
+    (0..1000isize, 0..1000isize, -999..1000isize, 0..4usize).prop_map(|(x, y, d, kind)| {
+        // Far enough from 0 that the end isn't negative either, the input has no `-`
+        let p = Point {
+            x: x + 1000,
+            y: y + 1000,
+        };
+        let q = match kind {
+            0 => Point { x: p.x + d, y: p.y },
+            1 => Point { x: p.x, y: p.y + d },
+            2 => Point {
+                x: p.x + d,
+                y: p.y + d,
+            },
+            _ => Point {
+                x: p.x + d,
+                y: p.y - d,
+            },
+        };
+        (p, q)
+    })
+}
+
+/// Increasing bounds, both included
+fn range<T: Ord + Copy + std::fmt::Debug>(
+    bounds: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = (T, T)> {
+    (bounds.clone(), bounds).prop_map(|(a, b)| (a.min(b), a.max(b)))
+}
+
+/// Cuboid bounds within the coordinates the day 22 parser accepts
+fn coordinates() -> impl Strategy<Value = (isize, isize)> {
+    range(-1_000_000..=1_000_000isize)
+}
+
+proptest! {
+    #[test]
+    fn any_text_is_parsed_or_rejected(input in "\\PC*") {
+        for challenge in challenges() {
+            let _ = challenge.check_input(&input);
+        }
+    }
+
+    /// Text made of the characters of the puzzle inputs gets further into the parsers
+    #[test]
+    fn input_like_text_is_parsed_or_rejected(input in "[0-9a-z ,.=:>|#\\[\\]\n-]*") {
+        for challenge in challenges() {
+            let _ = challenge.check_input(&input);
+        }
+    }
+
+    #[test]
+    fn depths_round_trip(depths in prop::collection::vec(any::<u32>(), 1..50)) {
+        let input: String = depths.iter().map(|d| format!("{}\n", d)).collect();
+        prop_assert_eq!(Day1::parse(&input).unwrap(), depths);
+    }
+
+    #[test]
+    fn commands_round_trip(
+        commands in prop::collection::vec(
+            prop_oneof![
+                (0..1000isize).prop_map(Command::Forward),
+                (0..1000isize).prop_map(Command::Down),
+                (0..1000isize).prop_map(Command::Up),
+            ],
+            1..50,
+        )
+    ) {
+        let input: String = commands.iter().map(|c| format!("{}\n", c)).collect();
+        prop_assert_eq!(Day2::parse(&input).unwrap(), commands);
+    }
+
+    #[test]
+    fn timers_round_trip(timers in prop::collection::vec(0..=6usize, 1..50)) {
+        let input = timers.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+        prop_assert_eq!(Day6::parse(&input).unwrap(), timers);
+    }
+
+    #[test]
+    fn positions_round_trip(positions in prop::collection::vec(any::<isize>(), 1..50)) {
+        let input = positions.iter().map(isize::to_string).collect::<Vec<_>>().join(",");
+        prop_assert_eq!(Day7::parse(&input).unwrap(), positions);
+    }
+
+    #[test]
+    fn starting_positions_round_trip(p1 in 1..=10usize, p2 in 1..=10usize) {
+        let input = format!(
+            "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+            p1, p2
+        );
+        prop_assert_eq!(Day21::parse(&input).unwrap(), (p1, p2));
+    }
+
+    #[test]
+    fn lines_round_trip(lines in prop::collection::vec(line(), 1..50)) {
+        let input: String = lines
+            .iter()
+            .map(|(p, q)| format!("{},{} -> {},{}\n", p.x, p.y, q.x, q.y))
+            .collect();
+        let parsed: Vec<_> = Day5::parse(&input)
+            .unwrap()
+            .iter()
+            .map(|line| line.endpoints())
+            .collect();
+        prop_assert_eq!(parsed, lines);
+    }
+
+    #[test]
+    fn target_area_round_trip(
+        x in range(-1_000_000..=1_000_000i64),
+        y in range(-1_000_000..0i64),
+    ) {
+        let input = format!("target area: x={}..{}, y={}..{}\n", x.0, x.1, y.0, y.1);
+        let target = Day17::parse(&input).unwrap();
+        prop_assert_eq!((target.x_range(), target.y_range()), (x, y));
+    }
+
+    #[test]
+    fn cuboids_round_trip(
+        steps in prop::collection::vec(
+            (any::<bool>(), [coordinates(), coordinates(), coordinates()]),
+            1..50,
+        )
+    ) {
+        let input: String = steps
+            .iter()
+            .map(|(on, [x, y, z])| {
+                format!(
+                    "{} x={}..{},y={}..{},z={}..{}\n",
+                    if *on { "on" } else { "off" },
+                    x.0, x.1, y.0, y.1, z.0, z.1
+                )
+            })
+            .collect();
+        let parsed: Vec<_> = Day22::parse(&input)
+            .unwrap()
+            .iter()
+            .map(|c| (c.is_on(), c.ranges()))
+            .collect();
+        prop_assert_eq!(parsed, steps);
+    }
+}